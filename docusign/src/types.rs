@@ -1695,11 +1695,11 @@ pub struct Accounts {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "dssValues"
     )]
-    pub dss_values: String,
+    pub dss_values: std::collections::HashMap<String, String>,
     /**
      * Account management
      */
@@ -12195,11 +12195,11 @@ pub struct AccountInformation {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "dssValues"
     )]
-    pub dss_values: String,
+    pub dss_values: std::collections::HashMap<String, String>,
     /**
      * Contains account Information.
      */
@@ -28332,11 +28332,11 @@ pub struct CommentPublish {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "threadAnchorKeys"
     )]
-    pub thread_anchor_keys: String,
+    pub thread_anchor_keys: std::collections::HashMap<String, String>,
     /**
      *
      */
@@ -82827,10 +82827,10 @@ pub struct Seal {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub configuration: String,
+    pub configuration: std::collections::HashMap<String, String>,
     /**
      * Set of information related to the electronic seal used by the Trust Service Provider (TSP).
      */
@@ -629,7 +629,45 @@ pub fn generate_client_generic_token(
         "{}?client_id={}&response_type=code&redirect_uri={}&state={}"
     };
 
-    let token_auth_template = get_token_auth_template(consent_pattern);
+    // MailChimp serves each account from its data center, which is only known
+    // once there is a token.
+    let after_access_token = if proper_name == "MailChimp" {
+        r#"
+    // The token is good even when its metadata cannot be read, the data
+    // center can be looked up again with set_data_center_from_metadata.
+    if let Err(e) = self.set_data_center_from_metadata().await {
+        log::warn!("failed to look up the data center of the token: {e}");
+    }
+"#
+    } else {
+        ""
+    };
+    let mut token_auth_template =
+        get_token_auth_template(consent_pattern, proper_name == "Zoom", after_access_token);
+
+    // Zoom Server-to-Server OAuth apps get their tokens from the account
+    // credentials grant, there is no refresh token. MailChimp clients can use
+    // an API key rather than a token. Google clients queue their requests when
+    // making calls for a batch and can have a field mask.
+    let (client_field, client_field_init) = if proper_name == "Zoom" {
+        token_auth_template.push_str(ZOOM_ACCOUNT_CREDENTIALS_TEMPLATE);
+        (
+            "account_id: String, token_endpoint: Option<String>,",
+            "account_id: String::new(), token_endpoint: None,",
+        )
+    } else if proper_name == "MailChimp" {
+        (
+            "api_key: String, metadata_endpoint: Option<String>,",
+            "api_key: String::new(), metadata_endpoint: None,",
+        )
+    } else if proper_name.starts_with("Google") {
+        (
+            "batch: Option<crate::batch::Queue>, fields: Option<String>,",
+            "batch: None, fields: None,",
+        )
+    } else {
+        ("", "")
+    };
 
     let server_block = servers.server_block();
     let server_arg = servers.server_arg();
@@ -657,6 +695,7 @@ pub struct Client {{
     client_id: String,
     client_secret: String,
     redirect_uri: String,
+    {client_field}
     {}
     auto_refresh: bool,
     #[cfg(feature = "middleware")]
@@ -737,6 +776,7 @@ impl Client {{
                         refresh_token: refresh_token.to_string(),
                         expires_at: None
                     }})),
+                    {client_field_init}
                     {}
                     auto_refresh: false,
                     client,
@@ -981,6 +1021,8 @@ where
                     refresh_token: refresh_token.to_string(),
                     expires_at: None
                 })),
+                batch: None,
+                fields: None,
                 auto_refresh: false,
                 client,
             }
@@ -1004,6 +1046,34 @@ pub fn generate_client_generic_api_key(
     let server_arg = servers.server_arg();
     let server_to_host = servers.host_from_server();
 
+    // Okta can also authenticate as an OAuth 2.0 service app, see auth.rs,
+    // SendGrid can act as a subuser and ShipBob as a channel, see headers.rs,
+    // and Stripe can expand the objects in every response, see expand.rs, and
+    // send a few headers with every request, see headers.rs.
+    let (client_field, client_field_init) = if proper_name == "Okta" {
+        (
+            "service_app: Option<crate::auth::ServiceApp>,",
+            "service_app: None,",
+        )
+    } else if proper_name == "SendGrid" {
+        ("on_behalf_of: Option<String>,", "on_behalf_of: None,")
+    } else if proper_name == "ShipBob" {
+        ("channel_id: Option<i64>,", "channel_id: None,")
+    } else if proper_name == "Stripe" {
+        (
+            "expand: Vec<String>,
+    stripe_version: Option<String>,
+    stripe_account: Option<String>,
+    idempotency_key: Option<String>,",
+            "expand: Vec::new(),
+                    stripe_version: None,
+                    stripe_account: None,
+                    idempotency_key: None,",
+        )
+    } else {
+        ("", "")
+    };
+
     format!(
         r#"use std::env;
 
@@ -1015,6 +1085,7 @@ pub struct Client {{
     host: String,
     host_override: Option<String>,
     token: String,
+    {client_field}
 
     #[cfg(feature = "middleware")]
     client: reqwest_middleware::ClientWithMiddleware,
@@ -1062,6 +1133,7 @@ impl Client {{
                     host,
                     host_override: None,
                     token: token.to_string(),
+                    {client_field_init}
 
                     client,
                 }}
@@ -1120,7 +1192,35 @@ impl Client {{
 }
 
 fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
-    let post_header_args = if !add_post_header.is_empty() {
+    let post_header_args = if proper_name == "Stripe" {
+        // Retries of a POST have to send the same idempotency key for Stripe
+        // to only act on it once, so it is set before the request reaches the
+        // retry middleware.
+        r#"if let Some(version) = &self.stripe_version {
+        req = req.header("Stripe-Version", version);
+    }
+    if let Some(account) = &self.stripe_account {
+        req = req.header("Stripe-Account", account);
+    }
+    if method == reqwest::Method::POST {
+        let key = self
+            .idempotency_key
+            .clone()
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        req = req.header("Idempotency-Key", key);
+    }"#
+        .to_string()
+    } else if proper_name == "SendGrid" {
+        r#"if let Some(on_behalf_of) = &self.on_behalf_of {
+        req = req.header("on-behalf-of", on_behalf_of);
+    }"#
+        .to_string()
+    } else if proper_name == "ShipBob" {
+        r#"if let Some(channel_id) = self.channel_id {
+        req = req.header("shipbob_channel_id", channel_id);
+    }"#
+        .to_string()
+    } else if !add_post_header.is_empty() {
         format!(
             r#"if method == reqwest::Method::POST {{
             req = req.header(
@@ -1135,12 +1235,6 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         String::new()
     };
 
-    let bearer = if proper_name == "Okta" {
-        "SSWS".to_string()
-    } else {
-        "Bearer".to_string()
-    };
-
     // Add auto refresh functionality to clients that support it
     let raw_request = if proper_name.starts_with("Google")
         || proper_name == "DocuSign"
@@ -1151,9 +1245,130 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         || proper_name == "Slack"
         || proper_name == "Zoom"
     {
-        get_shared_raw_functions_with_refresh("Bearer", &post_header_args)
+        // Google clients add their field mask to every request, and when in a
+        // batch hand their requests to it rather than sending them.
+        let make_request = if proper_name.starts_with("Google") {
+            r#"let mut req = self.make_request(&method, uri, message).await?;
+    if let Some(fields) = &self.fields {
+        // Requests that pick their own fields, such as those of a sync, keep them.
+        if !req.url().query_pairs().any(|(k, _)| k == "fields") {
+            req.url_mut().query_pairs_mut().append_pair("fields", fields);
+        }
+    }
+    if let Some(batch) = &self.batch {
+        return batch.enqueue(req).await;
+    }"#
+        } else {
+            "let req = self.make_request(&method, uri, message).await?;"
+        };
+        // MailChimp API keys are sent with basic auth, with any user name.
+        let auth = if proper_name == "MailChimp" {
+            r#"if self.api_key.is_empty() {
+        format!("Bearer {}", self.token.read().await.access_token)
+    } else {
+        let credentials = format!("anystring:{}", self.api_key);
+        format!(
+            "Basic {}",
+            base64::Engine::encode(&base64::engine::general_purpose::STANDARD, credentials)
+        )
+    }"#
+        } else {
+            r#"format!("Bearer {}", self.token.read().await.access_token)"#
+        };
+        get_shared_raw_functions_with_refresh(auth, &post_header_args, make_request)
+    } else if proper_name == "Okta" {
+        get_shared_raw_functions_okta(&post_header_args)
     } else {
-        get_shared_raw_functions_without_refresh(&bearer, &post_header_args)
+        // Stripe asks for the objects to expand in the query of GET requests,
+        // the ones that retrieve or list objects.
+        let request_url = if proper_name == "Stripe" {
+            r#"let (mut url, auth) = self.url_and_auth(uri).await?;
+    if method == reqwest::Method::GET {
+        for path in &self.expand {
+            url.query_pairs_mut().append_pair("expand[]", path);
+        }
+    }"#
+        } else {
+            "let (url, auth) = self.url_and_auth(uri).await?;"
+        };
+        get_shared_raw_functions_without_refresh("Bearer", &post_header_args, request_url)
+    };
+
+    // Okta pages until there is no `rel="next"` link, rather than until a page
+    // comes back empty.
+    let unfold_body = if proper_name == "Okta" {
+        r#"
+    let (mut link, crate::Response { mut status, mut headers, body: mut global_items }) = self.get_pages(uri).await?;
+    // Follow the `rel="next"` links until there are no more pages.
+    while let Some(url) = link {
+        let url = reqwest::Url::parse(&url.0)?;
+        let (new_link, mut response) = self.get_pages_url(&url).await?;
+        if response.body.is_empty() {
+            break;
+        }
+        global_items.append(&mut response.body);
+        link = new_link.filter(|l| l.0 != url.as_str());
+        status = response.status;
+        headers = response.headers;
+    }
+
+    Ok(Response::new(status, headers, global_items))
+"#
+    } else {
+        r#"
+    let mut global_items = Vec::new();
+    let (new_link, mut response) = self.get_pages(uri).await?;
+    let mut link = new_link;
+    while !response.body.is_empty() {
+        global_items.append(&mut response.body);
+        // We need to get the next link.
+        if let Some(url) = &link {
+            let url = reqwest::Url::parse(&url.0)?;
+            let (new_link, new_response) = self.get_pages_url(&url).await?;
+            link = new_link;
+            response = new_response;
+        }
+    }
+
+    Ok(Response::new(response.status, response.headers, global_items))
+"#
+    };
+
+    // Okta only exposes the next page through the `Link` header, so give the
+    // list endpoints a way to follow it lazily.
+    let pages_stream = if proper_name == "Okta" {
+        r#"
+/// Stream the items of paginated results, fetching the next page only once the current one has been consumed.
+#[allow(dead_code)]
+fn get_pages_stream<D>(&self, uri: &str) -> impl futures::Stream<Item = ClientResult<D>> + Unpin + Send + use<D>
+where
+    D: serde::de::DeserializeOwned + 'static + Send,
+{
+    use futures::TryStreamExt;
+
+    let client = self.clone();
+    Box::pin(
+        futures::stream::try_unfold(Some(uri.to_string()), move |next| {
+            let client = client.clone();
+            async move {
+                let url = match next {
+                    Some(url) => url,
+                    None => return ClientResult::Ok(None),
+                };
+                let (link, response) = client.get_pages::<D>(&url).await?;
+                if response.body.is_empty() {
+                    return Ok(None);
+                }
+                let next = link.map(|l| l.0).filter(|l| *l != url);
+                ClientResult::Ok(Some((futures::stream::iter(response.body.into_iter().map(Ok)), next)))
+            }
+        })
+        .try_flatten(),
+    )
+}
+"#
+    } else {
+        ""
     };
 
     format!(
@@ -1469,23 +1684,7 @@ async fn unfold<D>(
 ) -> ClientResult<crate::Response<Vec<D>>>
 where
     D: serde::de::DeserializeOwned + 'static + Send,
-{{
-    let mut global_items = Vec::new();
-    let (new_link, mut response) = self.get_pages(uri).await?;
-    let mut link = new_link;
-    while !response.body.is_empty() {{
-        global_items.append(&mut response.body);
-        // We need to get the next link.
-        if let Some(url) = &link {{
-            let url = reqwest::Url::parse(&url.0)?;
-            let (new_link, new_response) = self.get_pages_url(&url).await?;
-            link = new_link;
-            response = new_response;
-        }}
-    }}
-
-    Ok(Response::new(response.status, response.headers, global_items))
-}}
+{{{}}}
 
 #[allow(dead_code)]
 async fn get_pages<D>(&self, uri: &str) -> ClientResult<(Option<crate::utils::NextLink>, crate::Response<Vec<D>>)>
@@ -1510,7 +1709,7 @@ where
         Message::default(),
     ).await
 }}
-
+{}
 #[allow(dead_code)]
 async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
 where
@@ -1558,11 +1757,15 @@ where
         message,
     ).await
 }}"#,
-        raw_request
+        raw_request, unfold_body, pages_stream
     )
 }
 
-fn get_shared_raw_functions_without_refresh(bearer: &str, post_header_args: &str) -> String {
+fn get_shared_raw_functions_without_refresh(
+    bearer: &str,
+    post_header_args: &str,
+    request_url: &str,
+) -> String {
     format!(
         r#"
 async fn url_and_auth(
@@ -1581,7 +1784,7 @@ async fn request_raw(
     message: Message,
 ) -> ClientResult<reqwest::Response>
 {{
-    let (url, auth) = self.url_and_auth(uri).await?;
+    {request_url}
     let instance = <&Client>::clone(&self);
     let mut req = instance.client.request(method.clone(), url);
     // Set the default headers.
@@ -1616,7 +1819,73 @@ async fn request_raw(
     )
 }
 
-fn get_shared_raw_functions_with_refresh(bearer: &str, post_header_args: &str) -> String {
+// Okta can authenticate with either an `SSWS` API token or as an OAuth 2.0
+// service app, the latter may also need a DPoP proof on every request.
+fn get_shared_raw_functions_okta(post_header_args: &str) -> String {
+    format!(
+        r#"
+async fn url_and_auth(
+    &self,
+    uri: &str,
+) -> ClientResult<(reqwest::Url, Option<String>)> {{
+    let parsed_url = uri.parse::<reqwest::Url>()?;
+    let auth = match &self.service_app {{
+        Some(service_app) => service_app.authorization(self).await?,
+        None => format!("SSWS {{}}", self.token),
+    }};
+    Ok((parsed_url, Some(auth)))
+}}
+
+async fn request_raw(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    message: Message,
+) -> ClientResult<reqwest::Response>
+{{
+    let (url, auth) = self.url_and_auth(uri).await?;
+    let dpop = self.dpop_proof(&method, &url, auth.as_deref())?;
+    let instance = <&Client>::clone(&self);
+    let mut req = instance.client.request(method.clone(), url);
+    // Set the default headers.
+    req = req.header(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_static("application/json"),
+    );
+
+    if let Some(content_type) = &message.content_type {{
+        req = req.header(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_str(content_type).unwrap(),
+        );
+    }} else {{
+        req = req.header(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        );
+    }}
+
+    {post_header_args}
+    if let Some(auth_str) = auth {{
+        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    }}
+    if let Some(proof) = dpop {{
+        req = req.header("DPoP", proof);
+    }}
+    if let Some(body) = message.body {{
+        req = req.body(body);
+    }}
+    Ok(req.send().await?)
+}}
+"#
+    )
+}
+
+fn get_shared_raw_functions_with_refresh(
+    auth: &str,
+    post_header_args: &str,
+    make_request: &str,
+) -> String {
     format!(
         r#"
 async fn url_and_auth(
@@ -1625,7 +1894,7 @@ async fn url_and_auth(
 ) -> ClientResult<(reqwest::Url, Option<String>)> {{
     let parsed_url = uri.parse::<reqwest::Url>()?;
 
-    let auth = format!("{} {{}}", self.token.read().await.access_token);
+    let auth = {};
     Ok((parsed_url, Some(auth)))
 }}
 
@@ -1705,16 +1974,49 @@ async fn request_raw(
         }}
     }}
 
-    let req = self.make_request(&method, uri, message).await?;
+    {make_request}
     let resp = self.client.execute(req).await?;
 
     Ok(resp)
 }}"#,
-        bearer, post_header_args
+        auth, post_header_args
     )
 }
 
-fn get_token_auth_template<S: AsRef<str>>(consent_pattern: S) -> String {
+fn get_token_auth_template<S: AsRef<str>>(
+    consent_pattern: S,
+    account_credentials: bool,
+    after_access_token: &str,
+) -> String {
+    // Zoom sends its token requests through the client, to the token endpoint
+    // override when there is one, so they are retried and can be tested.
+    let (new_client, client, token_endpoint, form) = if account_credentials {
+        (
+            "",
+            "self.client",
+            "self.token_endpoint()",
+            r#".header(reqwest::header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(serde_urlencoded::to_string(params).unwrap())"#,
+        )
+    } else {
+        (
+            "let client = reqwest::Client::new();",
+            "client",
+            "TOKEN_ENDPOINT",
+            ".form(&params)",
+        )
+    };
+
+    let account_credentials = if account_credentials {
+        r#"
+    if !self.account_id.is_empty() {
+        return self.get_account_credentials_token().await;
+    }
+"#
+    } else {
+        ""
+    };
+
     format!(
         r#"
 /// Return a user consent url with an optional set of scopes.
@@ -1737,7 +2039,7 @@ pub fn user_consent_url(&self, scopes: &[String]) -> String {{
 
 /// Refresh an access token from a refresh token. Client must have a refresh token
 /// for this to work.
-pub async fn refresh_access_token(&self) -> ClientResult<AccessToken> {{
+pub async fn refresh_access_token(&self) -> ClientResult<AccessToken> {{{}
     let response = {{
         let refresh_token = &self.token.read().await.refresh_token;
 
@@ -1758,11 +2060,11 @@ pub async fn refresh_access_token(&self) -> ClientResult<AccessToken> {{
             ("client_secret", &self.client_secret),
             ("redirect_uri", &self.redirect_uri),
         ];
-        let client = reqwest::Client::new();
-        client
-            .post(TOKEN_ENDPOINT)
+        {new_client}
+        {client}
+            .post({token_endpoint})
             .headers(headers)
-            .form(&params)
+            {form}
             .basic_auth(&self.client_id, Some(&self.client_secret))
             .send()
             .await?
@@ -1799,11 +2101,11 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> ClientResul
         ("redirect_uri", &self.redirect_uri),
         ("state", state),
     ];
-    let client = reqwest::Client::new();
-    let resp = client
-        .post(TOKEN_ENDPOINT)
+    {new_client}
+    let resp = {client}
+        .post({token_endpoint})
         .headers(headers)
-        .form(&params)
+        {form}
         .basic_auth(&self.client_id, Some(&self.client_secret))
         .send()
         .await?;
@@ -1816,13 +2118,134 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> ClientResul
         refresh_token: t.refresh_token.clone(),
         expires_at: Self::compute_expires_at(t.expires_in),
     }};
-
+{}
     Ok(t)
 }}"#,
-        consent_pattern.as_ref()
+        consent_pattern.as_ref(),
+        account_credentials,
+        after_access_token
     )
 }
 
+const ZOOM_ACCOUNT_CREDENTIALS_TEMPLATE: &str = r#"
+
+/// Create a new Client struct for a Server-to-Server OAuth app.
+///
+/// Access tokens are requested with the `account_credentials` grant, and are
+/// renewed automatically shortly before they expire.
+pub fn new_server_to_server<A, I, K>(account_id: A, client_id: I, client_secret: K) -> Self
+where
+    A: ToString,
+    I: ToString,
+    K: ToString,
+{
+    let mut client = Client::new(client_id, client_secret, "", "", "");
+    client.account_id = account_id.to_string();
+    client.auto_refresh = true;
+    // There is no token yet, so make sure the first request gets one.
+    client.token = Arc::new(RwLock::new(InnerToken {
+        access_token: String::new(),
+        refresh_token: String::new(),
+        expires_at: Some(Instant::now()),
+    }));
+    client
+}
+
+/// Create a new Client struct for a Server-to-Server OAuth app from environment variables.
+///
+/// The following environment variables are expected to be set:
+///   * `ZOOM_ACCOUNT_ID`
+///   * `ZOOM_CLIENT_ID`
+///   * `ZOOM_CLIENT_SECRET`
+///
+/// # Panics
+///
+/// This function will panic if the expected environment variables can not be found
+pub fn new_server_to_server_from_env() -> Self {
+    let account_id = env::var("ZOOM_ACCOUNT_ID").expect("must set ZOOM_ACCOUNT_ID");
+    let client_id = env::var("ZOOM_CLIENT_ID").expect("must set ZOOM_CLIENT_ID");
+    let client_secret = env::var("ZOOM_CLIENT_SECRET").expect("must set ZOOM_CLIENT_SECRET");
+
+    Client::new_server_to_server(account_id, client_id, client_secret)
+}
+
+/// Get an access token for a Server-to-Server OAuth app. Tokens are valid for
+/// an hour and there is no refresh token, a new one is requested once the
+/// current one expires.
+///
+/// Concurrent callers wait for the same request rather than each making one.
+pub async fn get_account_credentials_token(&self) -> ClientResult<AccessToken> {
+    // Hold the lock across the request, whoever waited on it gets its token.
+    let mut token = self.token.write().await;
+    let now = Instant::now();
+    if let Some(expires_at) = token.expires_at {
+        if expires_at > now && !token.access_token.is_empty() {
+            return Ok(AccessToken {
+                token_type: "bearer".to_string(),
+                access_token: token.access_token.clone(),
+                expires_in: (expires_at - now + REFRESH_THRESHOLD).as_secs() as i64,
+                ..Default::default()
+            });
+        }
+    }
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.append(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_static("application/json"),
+    );
+
+    let params = [
+        ("grant_type", "account_credentials"),
+        ("account_id", &self.account_id),
+    ];
+    let resp = self
+        .client
+        .post(self.token_endpoint())
+        .headers(headers)
+        .header(reqwest::header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params).unwrap())
+        .basic_auth(&self.client_id, Some(&self.client_secret))
+        .send()
+        .await?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let headers = resp.headers().clone();
+        return Err(ClientError::HttpError {
+            status,
+            headers,
+            error: resp.text().await?,
+        });
+    }
+
+    // Unwrap the response.
+    let t: AccessToken = resp.json().await?;
+
+    *token = InnerToken {
+        access_token: t.access_token.clone(),
+        refresh_token: String::new(),
+        expires_at: Self::compute_expires_at(t.expires_in),
+    };
+
+    Ok(t)
+}
+
+/// Send token requests to `token_endpoint` rather than to Zoom, such as to a
+/// proxy. The host override of the API does not apply to them.
+pub fn with_token_endpoint<E>(&mut self, token_endpoint: E) -> &mut Self
+where
+    E: ToString,
+{
+    self.token_endpoint = Some(token_endpoint.to_string());
+    self
+}
+
+/// Where token requests go.
+fn token_endpoint(&self) -> &str {
+    self.token_endpoint.as_deref().unwrap_or(TOKEN_ENDPOINT)
+}"#;
+
 const CLIENT_AUTH_TEMPLATE: &str = r#"
 /// Get an access token from the code returned by the URL paramter sent to the
 /// redirect URL.
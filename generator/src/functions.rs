@@ -4,9 +4,9 @@ use anyhow::{Result, bail};
 use inflector::cases::{pascalcase::to_pascal_case, snakecase::to_snake_case};
 
 use crate::{
-    ExtractJsonMediaType, ParameterDataExt, ReferenceOrExt, TypeId, TypeSpace, clean_fn_name,
-    clean_name, client::generate_servers, get_parameter_data, make_plural, oid_to_object_name,
-    path_to_operation_id, struct_name, template::parse,
+    ExtractJsonMediaType, ParameterDataExt, ReferenceOrExt, TypeDetails, TypeId, TypeSpace,
+    clean_fn_name, clean_name, client::generate_servers, get_parameter_data, make_plural,
+    oid_to_object_name, path_to_operation_id, struct_name, template::parse,
 };
 
 #[derive(Debug, Default)]
@@ -276,7 +276,7 @@ pub fn generate_files(
             let (mut response_type, tid, inner_response_type, pagination_property) =
                 get_response_type(&od, ts, o)?;
 
-            if proper_name == "GitHub" && response_type == "crate::types::Data" {
+            if proper_name == "GitHub" && is_empty_object(ts, &tid) {
                 response_type = "()".to_string();
             }
             // We shouldn't ever have an optional response type, thats just annoying.
@@ -462,6 +462,33 @@ pub fn generate_files(
                     &fn_inner,
                     &fn_name,
                 ));
+
+                // Okta only exposes the next page through the `Link` header, so
+                // also give a lazy version that follows it one page at a time.
+                if proper_name == "Okta" && bounds.is_empty() && body_param.is_none() {
+                    let docs = get_fn_docs_stream(o, m, p, &fn_name)?;
+
+                    let mut stream_name = format!(
+                        "{}_stream",
+                        fn_name.replacen("_all", "", 1).trim_end_matches('_')
+                    );
+                    if fn_names.contains(&(stream_name.clone() + &tag)) {
+                        stream_name = format!("{stream_name}_all_stream");
+                    }
+                    fn_names.push(stream_name.clone() + &tag);
+
+                    let mut content = docs;
+                    content.push_str(&format!("pub fn {stream_name}(&self,"));
+                    content.push_str(&fn_params_str.join(" "));
+                    content.push_str(&format!(
+                        ") -> impl futures::Stream<Item = ClientResult<{}>> + Unpin + Send + use<> {{",
+                        frt.trim_start_matches("Vec<").trim_end_matches('>')
+                    ));
+                    content.push_str(&template);
+                    content.push_str("self.client.get_pages_stream(&url)");
+                    content.push('}');
+                    out.add_content(&content);
+                }
             }
 
             // Add this to our map of functions based on the tag name.
@@ -771,12 +798,16 @@ fn get_fn_params(
             if let openapiv3::Parameter::Query {
                 parameter_data: _,
                 allow_reserved: _,
-                style: openapiv3::QueryStyle::Form,
+                style,
                 // We can ignore the allow empty value, we support this by default and
                 // aren't strict about not allowing empty values on other parameters
                 // merely because specs cannot be trusted.
                 allow_empty_value: _,
             } = item
+                && (*style == openapiv3::QueryStyle::Form
+                    // Maps are sent as `name[key]=value`.
+                    || (*style == openapiv3::QueryStyle::DeepObject
+                        && typ.starts_with("&std::collections::HashMap<")))
             {
                 if nam == "ref"
                     || nam == "type"
@@ -1285,6 +1316,53 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
     Ok(out.trim().to_string())
 }
 
+fn get_fn_docs_stream(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    a("/**");
+    if let Some(summary) = &o.summary {
+        a(&format!(" * {}.", summary.trim_end_matches('.')));
+        a(" *");
+    }
+    a(&format!(
+        " * This function performs a `{m}` to the `{p}` endpoint."
+    ));
+    a(" *");
+    a(&format!(
+        " * As opposed to `{fn_name}`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed."
+    ));
+    if let Some(description) = &o.description {
+        a(" *");
+        a(&format!(" * {}", description.replace('\n', "\n * ")));
+    }
+    if let Some(external_docs) = &o.external_docs {
+        a(" *");
+        a(&format!(" * FROM: <{}>", external_docs.url));
+    }
+    a(" */");
+
+    Ok(out.trim().to_string())
+}
+
+/*
+ * Whether a type is an object without any properties, GitHub answers some
+ * requests with one.
+ */
+fn is_empty_object(ts: &TypeSpace, tid: &TypeId) -> bool {
+    match ts.id_to_entry.get(tid).map(|te| &te.details) {
+        Some(TypeDetails::Object(o, _)) => o.is_empty(),
+        Some(TypeDetails::NamedType(id, _)) => is_empty_object(ts, id),
+        _ => false,
+    }
+}
+
+/// Okta list functions still take a `limit`, the size of the pages they fetch,
+/// since the links to the next pages keep it.
 fn is_page_param(s: &str, proper_name: &str) -> bool {
     s == "page"
         || s == "per_page"
@@ -1297,7 +1375,7 @@ fn is_page_param(s: &str, proper_name: &str) -> bool {
         || s == "page_number"
         || s == "start"
         || s == "sync_token"
-        || s == "limit"
+        || (s == "limit" && proper_name != "Okta")
         || s == "ending_before"
         || (s == "after" && proper_name == "Okta")
         || (s == "starting_after" && proper_name == "Stripe")
@@ -1,4 +1,6 @@
+mod batch;
 mod client;
+mod fields;
 mod functions;
 mod template;
 mod types;
@@ -219,6 +221,9 @@ impl ParameterDataExt for openapiv3::ParameterData {
 
                         t
                     }
+                    openapiv3::ReferenceOr::Item(s) if is_string_map(s) => {
+                        "&std::collections::HashMap<String, String>".to_string()
+                    }
                     openapiv3::ReferenceOr::Item(s) => {
                         match &s.schema_kind {
                             SchemaKind::Type(Type::Boolean {}) => "bool".to_string(),
@@ -559,6 +564,11 @@ pub enum TypeDetails {
     NamedType(TypeId, openapiv3::SchemaData),
     Enum(Vec<String>, openapiv3::SchemaData),
     Array(TypeId, openapiv3::SchemaData),
+    /*
+     * An object with no properties of its own, only additional properties
+     * of the given type.
+     */
+    Map(TypeId, openapiv3::SchemaData),
     Optional(TypeId, openapiv3::SchemaData),
     /*
      * Object property names are sorted lexicographically to ensure a stable
@@ -620,6 +630,7 @@ impl TypeDetails {
             TypeDetails::NamedType(_, d) => d.description.as_ref(),
             TypeDetails::Enum(_, d) => d.description.as_ref(),
             TypeDetails::Array(_, d) => d.description.as_ref(),
+            TypeDetails::Map(_, d) => d.description.as_ref(),
             TypeDetails::Optional(_, d) => d.description.as_ref(),
             TypeDetails::Object(_, d) => d.description.as_ref(),
             TypeDetails::OneOf(_, d) => d.description.as_ref(),
@@ -667,6 +678,11 @@ impl PartialEq for TypeDetails {
                     return i == oi;
                 }
             }
+            TypeDetails::Map(i, _d) => {
+                if let TypeDetails::Map(oi, _od) = other {
+                    return i == oi;
+                }
+            }
             TypeDetails::Optional(i, _d) => {
                 if let TypeDetails::Optional(oi, _od) = other {
                     return i == oi;
@@ -799,6 +815,19 @@ impl TypeSpace {
                      */
                     format!("array of {}", self.describe(itid))
                 }
+                TypeDetails::Map(itid, _) => {
+                    if let Some(ite) = self.id_to_entry.get(itid) {
+                        if let Some(n) = &ite.name {
+                            return format!("map of {} <{}>", n, itid.0);
+                        }
+                    }
+
+                    /*
+                     * If there is no name attached, we should try a
+                     * recursive describe.
+                     */
+                    format!("map of {}", self.describe(itid))
+                }
                 TypeDetails::Optional(itid, _) => {
                     if let Some(ite) = self.id_to_entry.get(itid) {
                         if let Some(n) = &ite.name {
@@ -866,6 +895,7 @@ impl TypeSpace {
                 }
                 TypeDetails::Enum(_, schema_data) => Some(schema_data),
                 TypeDetails::Array(_, schema_data) => Some(schema_data),
+                TypeDetails::Map(_, schema_data) => Some(schema_data),
                 TypeDetails::Optional(id, schema_data) => {
                     let def: openapiv3::SchemaData = Default::default();
                     if def == *schema_data {
@@ -987,6 +1017,10 @@ impl TypeSpace {
                 TypeDetails::Array(itid, _) => {
                     Ok(format!("Vec<{}>", self.render_type(itid, in_mod)?))
                 }
+                TypeDetails::Map(itid, _) => Ok(format!(
+                    "std::collections::HashMap<String, {}>",
+                    self.render_type(itid, in_mod)?
+                )),
                 TypeDetails::Optional(itid, _) => {
                     let rt = self.render_type(itid, in_mod)?;
 
@@ -1005,6 +1039,7 @@ impl TypeSpace {
                     if rt == "String"
                         || rt.starts_with("Vec<")
                         || rt.starts_with("std::collections::HashMap<")
+                        || rt.starts_with("serde_json::Map<")
                         || rt == "bool"
                         || rt == "i32"
                         || rt == "i64"
@@ -1533,39 +1568,37 @@ impl TypeSpace {
                         }
                     });
 
-                    // TODO: this is a horrible fix just for google!
-                    if name == "user custom properties" {
-                        return Ok((
-                            Some(name.to_string()),
-                            TypeDetails::Basic(
-                                "std::collections::HashMap<String, \
-                                 std::collections::HashMap<String, serde_json::Value>>"
-                                    .to_string(),
-                                s.schema_data.clone(),
-                            ),
-                        ));
-                    }
-
                     if o.properties.is_empty() {
                         // TODO: make this work for when there is both.
-                        if let Some(openapiv3::AdditionalProperties::Schema(ad)) =
-                            &o.additional_properties
-                        {
-                            let desc = if let Some(ref d) = s.schema_data.description {
-                                d.to_string()
-                            } else {
-                                "".to_string()
-                            };
+                        match &o.additional_properties {
+                            Some(openapiv3::AdditionalProperties::Schema(ad))
+                                if !is_free_form(ad) =>
+                            {
+                                let desc = if let Some(ref d) = s.schema_data.description {
+                                    d.to_string()
+                                } else {
+                                    "".to_string()
+                                };
 
-                            // If this name already exists add additional properties to it.
-                            if self.name_to_id.get(&clean_name(&name)).is_some() {
-                                name = format!("{} additional properties", name);
+                                // If this name already exists add additional properties to it.
+                                if self.name_to_id.get(&clean_name(&name)).is_some() {
+                                    name = format!("{} additional properties", name);
+                                }
+                                let id = self.select(Some(&name), ad, &desc)?;
+                                return Ok((None, TypeDetails::Map(id, s.schema_data.clone())));
                             }
-                            let id = self.select(Some(&name), ad, &desc)?;
-                            return Ok((
-                                Some(name.to_string()),
-                                TypeDetails::NamedType(id, s.schema_data.clone()),
-                            ));
+                            Some(openapiv3::AdditionalProperties::Schema(_))
+                            | Some(openapiv3::AdditionalProperties::Any(true)) => {
+                                // Nothing is known about the values, keep them as JSON.
+                                return Ok((
+                                    None,
+                                    TypeDetails::Basic(
+                                        "serde_json::Map<String, serde_json::Value>".to_string(),
+                                        s.schema_data.clone(),
+                                    ),
+                                ));
+                            }
+                            _ => {}
                         }
                     }
 
@@ -2325,6 +2358,51 @@ fn r#gen(
     {
         a("pub mod traits;");
     }
+    if proper_name == "Okta" {
+        a("pub mod auth;");
+        a("pub mod log_stream;");
+    }
+    if proper_name == "SendGrid" {
+        a("pub mod contacts_csv;");
+        a("pub mod event_webhook;");
+        a("pub mod inbound_parse;");
+        a("pub mod mail;");
+    }
+    if proper_name == "Google Sheets" {
+        a("pub mod a1;");
+        a("pub mod updates;");
+    }
+    if proper_name.starts_with("Google") {
+        a("pub mod batch;");
+        a("pub mod fields;");
+    }
+    if proper_name == "Google Drive" {
+        a("pub mod download;");
+    }
+    if proper_name == "Google Cloud Resource Manager" {
+        a("pub mod long_running;");
+    }
+    if proper_name == "Google Admin" {
+        a("pub mod reconcile;");
+    }
+    if proper_name == "MailChimp" {
+        a("pub mod data_center;");
+    }
+    if proper_name == "SendGrid" || proper_name == "ShipBob" || proper_name == "Stripe" {
+        a("pub mod headers;");
+    }
+    if proper_name == "Stripe" {
+        a("pub mod expand;");
+    }
+    if proper_name == "Ramp" || proper_name == "Google Calendar" || proper_name == "Google Drive" {
+        a("pub mod sync;");
+    }
+    if proper_name == "Google Calendar"
+        || proper_name == "Google Drive"
+        || proper_name == "Google Admin"
+    {
+        a("pub mod push;");
+    }
     // Hopefully there is never a "tag" named after these reserved libs.
     a("pub mod types;");
     a("#[doc(hidden)]");
@@ -2440,12 +2518,129 @@ pub enum ClientError {"#);
         }
     }
 
-    // Google Drive only due to traits.rs
+    // Okta only due to auth.rs
+    if proper_name == "Okta" {
+        a(r#"
+        /// JWT errors from auth.rs
+        #[error(transparent)]
+        JsonWebTokenError(#[from] jsonwebtoken::errors::Error),
+        /// The private key of a service app could not be used
+        #[error("Invalid private key: {0}")]
+        InvalidPrivateKey(String),"#);
+    }
+
+    // SendGrid only due to mail.rs, event_webhook.rs, inbound_parse.rs and
+    // contacts_csv.rs
+    if proper_name == "SendGrid" {
+        a(r#"
+        /// A contacts import or export failed
+        #[error("{id}: contacts job failed: {message}")]
+        ContactJobFailed{id: String, message: String},
+        /// A contacts import or export did not finish in time
+        #[error("{id}: contacts job timed out")]
+        ContactJobTimeout{id: String},
+        /// A file of a contacts export could not be read
+        #[error("Invalid export: {0}")]
+        InvalidExport(String),
+        /// Contacts could not be imported as they are
+        #[error("Invalid import: {0}")]
+        InvalidImport(String),
+        /// A POST of the Inbound Parse webhook could not be read
+        #[error("Invalid inbound email: {0}")]
+        InvalidInboundEmail(String),
+        /// A mail is missing something or breaks one of SendGrid's limits
+        #[error("Invalid mail: {0}")]
+        InvalidMail(String),
+        /// The public key of the Event Webhook could not be read
+        #[error("Invalid public key: {0}")]
+        InvalidPublicKey(String),
+        /// A POST of the Event Webhook was not signed by SendGrid
+        #[error("Invalid signature")]
+        InvalidSignature,"#);
+    }
+
+    // MailChimp only due to data_center.rs
+    if proper_name == "MailChimp" {
+        a(r#"
+        /// An API key does not end with the data center it is for
+        #[error("Invalid API key")]
+        InvalidApiKey,"#);
+    }
+
+    // ShipBob only due to headers.rs
+    if proper_name == "ShipBob" {
+        a(r#"
+        /// None of the channels the token can use can write
+        #[error("No channel to write with")]
+        NoChannel,"#);
+    }
+
+    // Google only due to fields.rs
+    if proper_name.starts_with("Google") {
+        a(r#"
+        /// A field mask names a field the response does not have
+        #[error("Invalid field mask: {0}")]
+        InvalidFieldMask(String),"#);
+    }
+
+    // Google only due to batch.rs
+    if proper_name.starts_with("Google") {
+        a(r#"
+        /// The batch a call was queued in was dropped before it was sent
+        #[error("The batch was not sent")]
+        BatchNotSent,"#);
+    }
+
+    // Google Admin, Calendar and Drive only due to push.rs
+    if proper_name == "Google Admin"
+        || proper_name == "Google Calendar"
+        || proper_name == "Google Drive"
+    {
+        a(r#"
+        /// A watch response does not say which resource the channel is for
+        #[error("{id}: watch response is missing the channel resourceId")]
+        MissingResourceId{id: String},"#);
+    }
+
+    // Google Admin only due to reconcile.rs
+    if proper_name == "Google Admin" {
+        a(r#"
+        /// A desired member is of a group that neither exists nor is desired
+        #[error("{email}: group neither exists nor is desired")]
+        UnknownGroup{email: String},"#);
+    }
+
+    // Google Sheets only due to a1.rs and traits.rs
+    if proper_name == "Google Sheets" {
+        a(r#"
+        /// A range is not valid A1 notation, or a table is missing a column
+        #[error("Invalid range: {0}")]
+        InvalidRange(String),"#);
+    }
+
+    // Google Cloud Resource Manager only due to long_running.rs
+    if proper_name == "Google Cloud Resource Manager" {
+        a(r#"
+        /// A long-running operation finished with an error
+        #[error("{name}: operation failed with code {}: {}", .status.code, .status.message)]
+        OperationFailed{name: String, status: crate::types::Status},
+        /// A long-running operation did not finish in time
+        #[error("{name}: operation timed out")]
+        OperationTimeout{name: String},"#);
+    }
+
+    // Google Drive only due to traits.rs and download.rs
     if proper_name == "Google Drive" {
         a(r#"
         /// Google Drive not found
         #[error("{name:?}: Drive not found")]
         DriveNotFound{name: String},
+        /// The file is of a Google Workspace type that cannot be exported
+        #[error("{mime_type:?}: cannot be downloaded")]
+        NotDownloadable{mime_type: String},
+        /// IO errors from download.rs
+        #[error(transparent)]
+        IoError(#[from] std::io::Error),
         /// str convertion error
         #[error(transparent)]
         ToStrError(#[from] reqwest::header::ToStrError),"#);
@@ -2665,6 +2860,52 @@ fn struct_name(s: &str) -> String {
     }
 }
 
+/*
+ * Whether a schema says nothing about the values it allows, such as `{}` or
+ * an object without any properties.
+ */
+fn is_free_form(s: &openapiv3::ReferenceOr<openapiv3::Schema>) -> bool {
+    match s {
+        openapiv3::ReferenceOr::Reference { .. } => false,
+        openapiv3::ReferenceOr::Item(s) => match &s.schema_kind {
+            openapiv3::SchemaKind::Any(a) => a.typ.is_none() && a.properties.is_empty(),
+            openapiv3::SchemaKind::Type(openapiv3::Type::Object(o)) => {
+                o.properties.is_empty()
+                    && !matches!(
+                        o.additional_properties,
+                        Some(openapiv3::AdditionalProperties::Schema(_))
+                    )
+            }
+            _ => false,
+        },
+    }
+}
+
+/*
+ * Whether a schema is a map of strings, such as Stripe's `metadata`,
+ * possibly among other choices.
+ */
+fn is_string_map(s: &openapiv3::Schema) -> bool {
+    match &s.schema_kind {
+        openapiv3::SchemaKind::Type(openapiv3::Type::Object(o)) => {
+            if let Some(openapiv3::AdditionalProperties::Schema(ad)) = &o.additional_properties {
+                o.properties.is_empty()
+                    && matches!(
+                        ad.as_item().map(|s| &s.schema_kind),
+                        Some(openapiv3::SchemaKind::Type(openapiv3::Type::String(_)))
+                    )
+            } else {
+                false
+            }
+        }
+        openapiv3::SchemaKind::AnyOf { any_of }
+        | openapiv3::SchemaKind::OneOf { one_of: any_of } => any_of
+            .iter()
+            .any(|s| s.as_item().map(is_string_map).unwrap_or_default()),
+        _ => false,
+    }
+}
+
 fn clean_name(t: &str) -> String {
     let mut s = t;
     if t == "/" {
@@ -3351,6 +3592,51 @@ yup-oauth2 = "^8""#
                     .to_string();
             }
 
+            // Okta only due to auth.rs and log_stream.rs
+            let mut futures_lib = "".to_string();
+            let mut tokio_features = "".to_string();
+            if proper_name == "Okta" {
+                futures_lib = r#"
+base64 = "^0.21"
+futures = "0.3"
+p256 = "0.13"
+rand = "0.8.5"
+sha2 = "0.10""#
+                    .to_string();
+                tokio_features = r#", features = ["sync", "time"]"#.to_string();
+            }
+            // SendGrid only due to mail.rs, event_webhook.rs, inbound_parse.rs and
+            // contacts_csv.rs
+            if proper_name == "SendGrid" {
+                futures_lib = r#"
+base64 = "^0.21"
+encoding_rs = "0.8"
+flate2 = "1"
+futures = "0.3"
+p256 = "0.13""#
+                    .to_string();
+            }
+            // MailChimp only due to the basic auth of API keys
+            if proper_name == "MailChimp" {
+                futures_lib = r#"
+base64 = "^0.21""#
+                    .to_string();
+            }
+            // Google only due to batch.rs, and Google Drive's sync.rs
+            if proper_name.starts_with("Google") {
+                futures_lib = r#"
+futures = "0.3""#
+                    .to_string();
+            }
+            // Google Drive only due to download.rs
+            if proper_name == "Google Drive" {
+                tokio_features = r#", features = ["io-util"]"#.to_string();
+            }
+            // Google Cloud Resource Manager only due to long_running.rs
+            if proper_name == "Google Cloud Resource Manager" {
+                tokio_features = r#", features = ["time"]"#.to_string();
+            }
+
             let mut toml = root.clone();
             toml.push("Cargo.toml");
             let tomlout = format!(
@@ -3399,9 +3685,9 @@ schemars = {{ version = "0.8", features = ["bytes", "chrono", "url", "uuid1"] }}
 serde = {{ version = "1", features = ["derive"] }}
 serde_json = "1"
 serde_urlencoded = "^0.7"
-url = {{ version = "2", features = ["serde"] }}{}{}
+url = {{ version = "2", features = ["serde"] }}{}{}{}
 thiserror = "1"
-tokio = {{ version = "1.25.0", default-features = false }}
+tokio = {{ version = "1.25.0", default-features = false{} }}
 
 [dev-dependencies]
 base64 = "^0.21"
@@ -3416,7 +3702,15 @@ wiremock = "0.5.17"
 all-features = true
 rustdoc-args = ["--cfg", "docsrs"]
 "#,
-                name, description, version, name, output_dir, uuid_lib, yup_oauth2_lib
+                name,
+                description,
+                version,
+                name,
+                output_dir,
+                uuid_lib,
+                yup_oauth2_lib,
+                futures_lib,
+                tokio_features
             );
             save(&toml, tomlout.as_str())?;
 
@@ -3518,6 +3812,19 @@ rustdoc-args = ["--cfg", "docsrs"]
             utilsrs.push("utils.rs");
             save(utilsrs, utils.as_str())?;
 
+            /*
+             * Create the batch and fields modules of the Google crates:
+             */
+            if proper_name.starts_with("Google") {
+                let mut batchrs = src.clone();
+                batchrs.push("batch.rs");
+                save(batchrs, batch::generate_batch(&proper_name).as_str())?;
+
+                let mut fieldsrs = src.clone();
+                fieldsrs.push("fields.rs");
+                save(fieldsrs, fields::generate_fields(&proper_name).as_str())?;
+            }
+
             /*
              * Create the Rust source types file containing the generated types:
              */
@@ -85,6 +85,11 @@ impl Template {
                         r#"if !{}.is_empty() {{ query_args.push(("{}".to_string(), {}.join(" "))); }}"#,
                         nam, prop, nam
                     ));
+                } else if value.starts_with("&std::collections::HashMap<") {
+                    a(&format!(
+                        r#"for (key, value) in {} {{ query_args.push((format!("{}[{{}}]", key), value.to_string())); }}"#,
+                        nam, prop
+                    ));
                 } else {
                     a(&format!(
                         r#"if !{}.to_string().is_empty() {{  query_args.push(("{}".to_string(), {}.to_string())); }}"#,
@@ -190,6 +190,7 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                 || rt.starts_with("Option<")
                                 || rt.starts_with("HashMap<")
                                 || rt.starts_with("std::collections::HashMap<")
+                                || rt.starts_with("serde_json::Map<")
                             {
                                 a(r#"#[serde(default,"#);
                                 if rt == "String" {
@@ -200,8 +201,12 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                       deserialize_with = "crate::utils::deserialize_null_vector::deserialize","#);
                                 } else if rt.starts_with("std::collections::HashMap<") {
                                     a(
-                                        r#"skip_serializing_if = "std::collections::HashMap::is_empty","#,
+                                        r#"skip_serializing_if = "std::collections::HashMap::is_empty",
+                                      deserialize_with = "crate::utils::deserialize_null_map::deserialize","#,
                                     );
+                                } else if rt.starts_with("serde_json::Map<") {
+                                    a(r#"skip_serializing_if = "serde_json::Map::is_empty",
+                                      deserialize_with = "crate::utils::deserialize_null_map::deserialize","#);
                                 } else if rt.starts_with("Option<url::Url") {
                                     a(r#"skip_serializing_if = "Option::is_none",
                                       deserialize_with = "crate::utils::deserialize_empty_url::deserialize","#);
@@ -332,6 +337,7 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                 TypeDetails::Unknown => {}
                 TypeDetails::NamedType(..) => {}
                 TypeDetails::Array(..) => {}
+                TypeDetails::Map(..) => {}
                 TypeDetails::Optional(..) => {}
             }
         }
@@ -400,6 +406,16 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
                     .trim_end_matches('>')
                     .replace("serde_json::", "")
             )
+        } else if name.starts_with("std::collections::HashMap<") {
+            format!(
+                "{}Map",
+                struct_name(
+                    name.trim_start_matches("std::collections::HashMap<String, ")
+                        .trim_end_matches('>')
+                )
+            )
+        } else if name.starts_with("serde_json::Map<") {
+            "Map".to_string()
         } else if name.starts_with("serde_json") {
             "Value".to_string()
         } else {
@@ -426,6 +442,7 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     // Render the implementation to easily unpack these things for the end user.
     a(&format!("impl {} {{", sn));
     for (fn_name, name) in &name_map {
+        let accessor = accessor_name(fn_name, name);
         if name_map.len() > 1 {
             a(&format!(
                 r#"pub fn {}(&self) -> Option<&{}> {{
@@ -434,14 +451,7 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
                             }}
                             None
                         }}"#,
-                to_snake_case(name)
-                    .replace("f_64", "f64")
-                    .replace("f_32", "f32")
-                    .replace("i_64", "i64")
-                    .replace("i_32", "i32"),
-                name,
-                sn,
-                fn_name,
+                accessor, name, sn, fn_name,
             ));
         } else {
             a(&format!(
@@ -450,14 +460,7 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
                             Some(ref_)
 
                         }}"#,
-                to_snake_case(name)
-                    .replace("f_64", "f64")
-                    .replace("f_32", "f32")
-                    .replace("i_64", "i64")
-                    .replace("i_32", "i32"),
-                name,
-                sn,
-                fn_name,
+                accessor, name, sn, fn_name,
             ));
         }
         a("");
@@ -477,6 +480,7 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
             || name == "bool"
             || name == "String"
             || name.starts_with("Vec<")
+            || name.starts_with("std::collections::HashMap<")
         {
             a(&format!(
                 r#"impl std::convert::From<{}> for {} {{
@@ -490,7 +494,7 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
         }
     }
 
-    for name in name_map.values() {
+    for (fn_name, name) in &name_map {
         if name == "i64" || name == "i32" || name == "f64" || name == "f32" || name == "bool" {
             a(&format!(
                 r#"impl std::convert::From<{}> for {} {{
@@ -507,7 +511,10 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
                     .replace("i_64", "i64")
                     .replace("i_32", "i32"),
             ));
-        } else if name == "String" || name.starts_with("Vec<") {
+        } else if name == "String"
+            || name.starts_with("Vec<")
+            || name.starts_with("std::collections::HashMap<")
+        {
             a(&format!(
                 r#"impl std::convert::From<{}> for {} {{
                                     fn from(f: {}) -> Self {{
@@ -517,7 +524,7 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
                 sn,
                 name,
                 sn,
-                to_snake_case(name)
+                accessor_name(fn_name, name)
             ));
         }
         a("");
@@ -526,6 +533,21 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     out
 }
 
+/*
+ * The name of the function that returns the given variant of a one of type.
+ */
+fn accessor_name(fn_name: &str, name: &str) -> String {
+    if name.starts_with("std::collections::HashMap<") || name.starts_with("serde_json::Map<") {
+        return to_snake_case(fn_name);
+    }
+
+    to_snake_case(name)
+        .replace("f_64", "f64")
+        .replace("f_32", "f32")
+        .replace("i_64", "i64")
+        .replace("i_32", "i32")
+}
+
 fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
     let mut out = String::new();
 
@@ -566,6 +588,16 @@ fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
                     .trim_end_matches('>')
                     .replace("serde_json::", "")
             )
+        } else if name.starts_with("std::collections::HashMap<") {
+            format!(
+                "{}Map",
+                struct_name(
+                    name.trim_start_matches("std::collections::HashMap<String, ")
+                        .trim_end_matches('>')
+                )
+            )
+        } else if name.starts_with("serde_json::Map<") {
+            "Map".to_string()
         } else if name.starts_with("serde_json") {
             "Value".to_string()
         } else {
@@ -20,7 +20,9 @@ impl Emojis {
      *
      * FROM: <https://docs.github.com/rest/reference/emojis#get-emojis>
      */
-    pub async fn get(&self) -> ClientResult<crate::Response<String>> {
+    pub async fn get(
+        &self,
+    ) -> ClientResult<crate::Response<std::collections::HashMap<String, String>>> {
         let url = self.client.url("/emojis", None);
         self.client
             .get(
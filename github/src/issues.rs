@@ -1816,7 +1816,7 @@ impl Issues {
         issue_number: i64,
         per_page: i64,
         page: i64,
-    ) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
+    ) -> ClientResult<crate::Response<Vec<crate::types::Value>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1861,7 +1861,7 @@ impl Issues {
         owner: &str,
         repo: &str,
         issue_number: i64,
-    ) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
+    ) -> ClientResult<crate::Response<Vec<crate::types::Value>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/timeline",
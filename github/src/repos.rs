@@ -6062,7 +6062,7 @@ impl Repos {
         &self,
         owner: &str,
         repo: &str,
-    ) -> ClientResult<crate::Response<i64>> {
+    ) -> ClientResult<crate::Response<std::collections::HashMap<String, i64>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/languages",
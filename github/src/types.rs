@@ -670,21 +670,26 @@ pub struct ValidationError {
     pub message: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Data {}
-
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Request {
     /**
      * The request headers sent with the webhook delivery.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub headers: Option<Data>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub headers: serde_json::Map<String, serde_json::Value>,
     /**
      * The request headers sent with the webhook delivery.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub payload: Option<Data>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub payload: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -692,8 +697,12 @@ pub struct Response {
     /**
      * The request headers sent with the webhook delivery.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub headers: Option<Data>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub headers: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3168,6 +3177,9 @@ pub struct RunnerApplication {
     pub temp_download_token: String,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Value {}
+
 /// Authentication Token
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuthenticationToken {
@@ -3178,10 +3190,10 @@ pub struct AuthenticationToken {
     )]
     pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The request headers sent with the webhook delivery.
+     * Authentication Token
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Data>,
+    pub permissions: Option<Value>,
     /**
      * Authentication Token
      */
@@ -3308,8 +3320,15 @@ pub struct AuditLogEvent {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub created_at: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub data: Option<Data>,
+    /**
+     * The request headers sent with the webhook delivery.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub data: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4475,7 +4494,12 @@ pub struct BaseGist {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    pub files: Files,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub files: std::collections::HashMap<String, Files>,
     /**
      * Base Gist
      */
@@ -4968,7 +4992,12 @@ pub struct Gist {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    pub files: Files,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub files: std::collections::HashMap<String, Files>,
     /**
      * Gist
      */
@@ -5136,8 +5165,15 @@ pub struct GistSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub files: Option<FilesAdditionalProperties>,
+    /**
+     * Gist Simple
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub files: std::collections::HashMap<String, FilesAdditionalProperties>,
     /**
      * Gist
      */
@@ -6555,7 +6591,7 @@ pub struct MinimalRepository {
      * Minimal Repository
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub template_repository: Option<Data>,
+    pub template_repository: Option<Value>,
     /**
      * Minimal Repository
      */
@@ -9485,7 +9521,7 @@ pub struct TeamRepository {
      * A team's access to a repository.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub template_repository: Option<Data>,
+    pub template_repository: Option<Value>,
     /**
      * A team's access to a repository.
      */
@@ -10327,7 +10363,7 @@ pub struct FullRepository {
      * Full Repository
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub template_repository: Option<Data>,
+    pub template_repository: Option<Value>,
     /**
      * Full Repository
      */
@@ -11132,7 +11168,7 @@ pub struct PendingDeployment {
 /// All of the following types:
 ///
 /// - `String`
-/// - `Data`
+/// - `serde_json::Map<String, serde_json::Value>`
 ///
 /// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 ///
@@ -11140,12 +11176,15 @@ pub struct PendingDeployment {
 #[serde(untagged)]
 pub enum PayloadOneOf {
     String(String),
-    Data(Data),
+    /**
+     * The request headers sent with the webhook delivery.
+     */
+    Map(serde_json::Map<String, serde_json::Value>),
 }
 
 impl PayloadOneOf {
-    pub fn data(&self) -> Option<&Data> {
-        if let PayloadOneOf::Data(ref_) = self {
+    pub fn map(&self) -> Option<&serde_json::Map<String, serde_json::Value>> {
+        if let PayloadOneOf::Map(ref_) = self {
             return Some(ref_);
         }
         None
@@ -21821,7 +21860,7 @@ impl Op {
 ///
 /// - `String`
 /// - `Vec<String>`
-/// - `Data`
+/// - `Value`
 ///
 /// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 ///
@@ -21833,26 +21872,26 @@ pub enum ScimUserOperationsValueOneOf {
      * The list of events for the GitHub app
      */
     StringVector(Vec<String>),
-    Data(Data),
+    Value(Value),
 }
 
 impl ScimUserOperationsValueOneOf {
-    pub fn data(&self) -> Option<&Data> {
-        if let ScimUserOperationsValueOneOf::Data(ref_) = self {
+    pub fn string(&self) -> Option<&String> {
+        if let ScimUserOperationsValueOneOf::String(ref_) = self {
             return Some(ref_);
         }
         None
     }
 
-    pub fn string(&self) -> Option<&String> {
-        if let ScimUserOperationsValueOneOf::String(ref_) = self {
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ScimUserOperationsValueOneOf::StringVector(ref_) = self {
             return Some(ref_);
         }
         None
     }
 
-    pub fn vec_string(&self) -> Option<&Vec<String>> {
-        if let ScimUserOperationsValueOneOf::StringVector(ref_) = self {
+    pub fn value(&self) -> Option<&Value> {
+        if let ScimUserOperationsValueOneOf::Value(ref_) = self {
             return Some(ref_);
         }
         None
@@ -25132,7 +25171,12 @@ pub struct GistsCreateRequest {
     /**
      * Names and content for the files that make up the gist
      */
-    pub files: FilesAdditionalPropertiesData,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub files: std::collections::HashMap<String, FilesAdditionalPropertiesData>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub public: Option<PublicOneOf>,
 }
@@ -25162,8 +25206,12 @@ pub struct GistsUpdateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub files: Option<FilesAdditionalPropertiesDataType>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub files: std::collections::HashMap<String, FilesAdditionalPropertiesDataType>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25871,13 +25919,13 @@ pub struct AppsListInstallationsResponse {
 
 /// All of the following types are flattened into one object:
 ///
-/// - `Data`
+/// - `Value`
 /// - `InteractionLimits`
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InteractionsGetRestrictionsResponseAnyOf {
     #[serde(flatten)]
-    pub data: Data,
+    pub value: Value,
     /**
      * Interaction limit settings.
      */
@@ -27204,10 +27252,10 @@ pub struct ActionsListRepoWorkflowsResponse {
 pub struct ActionsCreateWorkflowDispatchRequest {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub inputs: String,
+    pub inputs: std::collections::HashMap<String, String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28470,8 +28518,15 @@ pub struct ReposCreateDeploymentStatusRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateDispatchEventRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub client_payload: Option<Data>,
+    /**
+     * The request headers sent with the webhook delivery.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub client_payload: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30750,7 +30805,7 @@ pub struct EnterpriseAdminUpdateAttributeUserRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
         rename = "Operations"
     )]
-    pub operations: Vec<Data>,
+    pub operations: Vec<Value>,
     /**
      * The list of events for the GitHub app
      */
@@ -30815,7 +30870,7 @@ pub struct ScimProvisionInviteUserRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Value {
+pub struct ValueData {
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -30855,7 +30910,7 @@ pub struct Value {
 ///
 /// - `String`
 /// - `Vec<ScimUserEmails>`
-/// - `Value`
+/// - `ValueData`
 ///
 /// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 ///
@@ -30867,7 +30922,7 @@ pub enum ScimUpdateAttributeUserRequestOperationsValueOneOf {
      * user emails
      */
     ScimUserEmailsVector(Vec<ScimUserEmails>),
-    Value(Value),
+    ValueData(ValueData),
 }
 
 impl ScimUpdateAttributeUserRequestOperationsValueOneOf {
@@ -30886,8 +30941,8 @@ impl ScimUpdateAttributeUserRequestOperationsValueOneOf {
         None
     }
 
-    pub fn value(&self) -> Option<&Value> {
-        if let ScimUpdateAttributeUserRequestOperationsValueOneOf::Value(ref_) = self {
+    pub fn value_data(&self) -> Option<&ValueData> {
+        if let ScimUpdateAttributeUserRequestOperationsValueOneOf::ValueData(ref_) = self {
             return Some(ref_);
         }
         None
@@ -477,6 +477,16 @@ impl Visitor<'_> for I64Visitor {
     {
         Ok(value as i64)
     }
+
+    // Some APIs, Google's in particular, encode 64-bit integers as strings.
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        value
+            .parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(value), &self))
+    }
 }
 
 pub mod deserialize_null_i64 {
@@ -494,7 +504,7 @@ pub mod deserialize_null_i64 {
         D: Deserializer<'de>,
     {
         let s = deserializer
-            .deserialize_i64(crate::utils::I64Visitor)
+            .deserialize_any(crate::utils::I64Visitor)
             .unwrap_or_default();
 
         Ok(s)
@@ -766,6 +776,25 @@ pub mod deserialize_null_vector {
     }
 }
 
+pub mod deserialize_null_map {
+    use serde::{self, Deserialize, Deserializer};
+
+    // The signature of a deserialize_with function must follow the pattern:
+    //
+    //    fn deserialize<'de, D>(D) -> Result<T, D::Error>
+    //    where
+    //        D: Deserializer<'de>
+    //
+    // although it may also be generic over the output types T.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Deserialize<'de> + Default,
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::next_link;
@@ -0,0 +1,348 @@
+//! Batch requests.
+//!
+//! A batch sends many calls as one `multipart/mixed` request. Calls are queued
+//! with the generated functions of a [`Client`] given to the closure passed to
+//! [`Batch::queue`], their requests are collected instead of sent and each
+//! call gets its own typed result once the batch is sent.
+//!
+//! ```ignore
+//! let mut batch = client.batch();
+//! let calls: Vec<_> = users
+//!     .into_iter()
+//!     .map(|user| {
+//!         batch.queue(move |client| async move {
+//!             client.users().update(&user.primary_email, &user).await
+//!         })
+//!     })
+//!     .collect();
+//! batch.send().await?;
+//!
+//! for call in calls {
+//!     let user = call.take().unwrap()?.body;
+//! }
+//! ```
+//!
+//! FROM: <https://developers.google.com/admin-sdk/directory/v1/guides/batch>
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::Poll,
+};
+
+use futures::channel::oneshot;
+
+use crate::{Client, ClientError, ClientResult};
+
+const BATCH_PATH: &str = "/batch/admin/directory_v1";
+
+/// Google rejects batches of more than 100 calls, larger batches are sent as
+/// several requests.
+pub const MAX_BATCH_SIZE: usize = 100;
+
+type Pending = (
+    reqwest::Request,
+    oneshot::Sender<ClientResult<reqwest::Response>>,
+);
+
+/// Collects the requests of the calls in a batch.
+#[derive(Clone, Default)]
+pub(crate) struct Queue(Arc<Mutex<Vec<Pending>>>);
+
+impl Queue {
+    /// Queue a request and wait for its response from the batch.
+    pub(crate) async fn enqueue(&self, req: reqwest::Request) -> ClientResult<reqwest::Response> {
+        let (tx, rx) = oneshot::channel();
+        self.0.lock().unwrap().push((req, tx));
+
+        // The sender is dropped without a response when the batch is.
+        rx.await.map_err(|_| ClientError::BatchNotSent)?
+    }
+
+    fn len(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+
+    fn take(&self) -> Vec<Pending> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+/// The result of a call in a batch.
+pub struct Call<T> {
+    result: Arc<Mutex<Option<ClientResult<T>>>>,
+}
+
+impl<T> Call<T> {
+    /// The result of the call, `None` until the batch is sent.
+    pub fn take(&self) -> Option<ClientResult<T>> {
+        self.result.lock().unwrap().take()
+    }
+}
+
+/// A batch of calls, see the [module documentation](self).
+pub struct Batch {
+    client: Client,
+    queue: Queue,
+    calls: Vec<Pin<Box<dyn Future<Output = ()> + Send>>>,
+}
+
+impl Client {
+    /// Start a batch of calls.
+    pub fn batch(&self) -> Batch {
+        let queue = Queue::default();
+        let mut client = self.clone();
+        client.batch = Some(queue.clone());
+
+        Batch {
+            client,
+            queue,
+            calls: Vec::new(),
+        }
+    }
+}
+
+impl Batch {
+    /// Queue a call. `call` is given a client whose requests are added to the
+    /// batch and should make the call with it.
+    pub fn queue<F, Fut, T>(&mut self, call: F) -> Call<T>
+    where
+        F: FnOnce(Client) -> Fut,
+        Fut: Future<Output = ClientResult<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let result = Arc::new(Mutex::new(None));
+        let slot = result.clone();
+        let fut = call(self.client.clone());
+        self.calls.push(Box::pin(async move {
+            let r = fut.await;
+            *slot.lock().unwrap() = Some(r);
+        }));
+
+        Call { result }
+    }
+
+    /// The number of queued calls.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Send the queued calls, their results are then available from their
+    /// [`Call`].
+    ///
+    /// Errors are only returned when the batch itself could not be sent, the
+    /// errors of the calls are in their results. Calls that make more than one
+    /// request, such as the `*_all` functions, send their next request in
+    /// another batch.
+    pub async fn send(self) -> ClientResult<()> {
+        let Batch {
+            client,
+            queue,
+            calls,
+        } = self;
+        let mut calls: Vec<_> = calls.into_iter().map(Some).collect();
+
+        loop {
+            // Run every call until it either finished or is waiting on the
+            // response to its request.
+            std::future::poll_fn(|cx| {
+                for call in calls.iter_mut() {
+                    if let Some(fut) = call {
+                        if fut.as_mut().poll(cx).is_ready() {
+                            *call = None;
+                        }
+                    }
+                }
+
+                if queue.len() >= calls.iter().flatten().count() {
+                    Poll::Ready(())
+                } else {
+                    Poll::Pending
+                }
+            })
+            .await;
+
+            let mut pending = queue.take();
+            if pending.is_empty() {
+                return Ok(());
+            }
+            while !pending.is_empty() {
+                let rest = pending.split_off(pending.len().min(MAX_BATCH_SIZE));
+                send_batch(&client, pending).await?;
+                pending = rest;
+            }
+        }
+    }
+}
+
+async fn send_batch(client: &Client, pending: Vec<Pending>) -> ClientResult<()> {
+    let boundary = format!("batch_{}", uuid::Uuid::new_v4().simple());
+    let mut body = Vec::new();
+    for (i, (req, _)) in pending.iter().enumerate() {
+        write_part(&mut body, &boundary, i, req);
+    }
+    body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+
+    let mut url: reqwest::Url = client.url("", None).parse()?;
+    url.set_path(BATCH_PATH);
+    url.set_query(None);
+    let req = client
+        .make_request(
+            &reqwest::Method::POST,
+            url.as_str(),
+            crate::Message {
+                body: Some(reqwest::Body::from(body)),
+                content_type: Some(format!("multipart/mixed; boundary={boundary}")),
+            },
+        )
+        .await?;
+    let resp = client.client.execute(req).await?;
+
+    let status = resp.status();
+    let headers = resp.headers().clone();
+    let body = resp.bytes().await?;
+    if !status.is_success() {
+        for (_, tx) in pending {
+            let _ = tx.send(Err(ClientError::HttpError {
+                status,
+                headers: headers.clone(),
+                error: String::from_utf8_lossy(&body).into(),
+            }));
+        }
+        return Ok(());
+    }
+
+    let boundary = headers
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|c| c.to_str().ok())
+        .and_then(|c| {
+            c.split(';')
+                .find_map(|p| p.trim().strip_prefix("boundary="))
+                .map(|b| b.trim_matches('"').to_string())
+        })
+        .unwrap_or_default();
+    let mut responses = parse_parts(&body, &boundary);
+
+    for (i, (_, tx)) in pending.into_iter().enumerate() {
+        let resp = match responses.iter().position(|(id, _)| *id == Some(i)) {
+            Some(p) => Ok(responses.remove(p).1.into()),
+            None => Err(ClientError::HttpError {
+                status,
+                headers: headers.clone(),
+                error: format!("no response for call {i} in the batch response"),
+            }),
+        };
+        let _ = tx.send(resp);
+    }
+
+    Ok(())
+}
+
+fn write_part(body: &mut Vec<u8>, boundary: &str, i: usize, req: &reqwest::Request) {
+    let url = req.url();
+    let target = match url.query().filter(|q| !q.is_empty()) {
+        Some(q) => format!("{}?{q}", url.path()),
+        None => url.path().to_string(),
+    };
+
+    body.extend_from_slice(
+        format!(
+            "--{boundary}\r\nContent-Type: application/http\r\nContent-ID: <item{i}>\r\n\r\n{} {target} HTTP/1.1\r\n",
+            req.method()
+        )
+        .as_bytes(),
+    );
+    for (name, value) in req.headers() {
+        body.extend_from_slice(name.as_str().as_bytes());
+        body.extend_from_slice(b": ");
+        body.extend_from_slice(value.as_bytes());
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(b"\r\n");
+    if let Some(b) = req.body().and_then(|b| b.as_bytes()) {
+        body.extend_from_slice(b);
+    }
+    body.extend_from_slice(b"\r\n");
+}
+
+/// Split a `multipart/mixed` batch response into the responses it holds,
+/// along with the index of the call they answer.
+fn parse_parts(body: &[u8], boundary: &str) -> Vec<(Option<usize>, http::Response<Vec<u8>>)> {
+    let delimiter = format!("--{boundary}");
+    let mut parts = split(body, delimiter.as_bytes());
+    // Whatever comes before the first delimiter is not a part.
+    if !parts.is_empty() {
+        parts.remove(0);
+    }
+
+    parts
+        .into_iter()
+        .take_while(|p| !p.starts_with(b"--"))
+        .filter_map(|part| {
+            let part = trim_crlf(part);
+            let (part_headers, http) = split_once(part, b"\r\n\r\n")?;
+            let id = header_lines(part_headers)
+                .find(|(n, _)| n.eq_ignore_ascii_case("content-id"))
+                .and_then(|(_, v)| {
+                    v.trim_matches(|c| c == '<' || c == '>')
+                        .strip_prefix("response-item")?
+                        .parse()
+                        .ok()
+                });
+
+            let (head, body) = split_once(http, b"\r\n\r\n").unwrap_or((http, b""));
+            let mut lines = header_lines(head);
+            let (status_line, _) = lines.next()?;
+            let status = status_line.split_whitespace().nth(1)?.parse::<u16>().ok()?;
+
+            let mut resp = http::Response::builder().status(status);
+            for (name, value) in lines {
+                resp = resp.header(name, value);
+            }
+
+            Some((id, resp.body(trim_crlf(body).to_vec()).ok()?))
+        })
+        .collect()
+}
+
+/// The `Name: value` lines of a header block, a line without a colon, such as
+/// a status line, is returned as the name.
+fn header_lines(head: &[u8]) -> impl Iterator<Item = (&str, &str)> {
+    std::str::from_utf8(head)
+        .unwrap_or_default()
+        .split("\r\n")
+        .filter(|l| !l.is_empty())
+        .map(|l| match l.split_once(':') {
+            Some((n, v)) if !n.contains(' ') => (n.trim(), v.trim()),
+            _ => (l, ""),
+        })
+}
+
+fn split<'a>(mut haystack: &'a [u8], needle: &[u8]) -> Vec<&'a [u8]> {
+    let mut parts = Vec::new();
+    while let Some((before, after)) = split_once(haystack, needle) {
+        parts.push(before);
+        haystack = after;
+    }
+    parts.push(haystack);
+    parts
+}
+
+fn split_once<'a>(haystack: &'a [u8], needle: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let i = haystack.windows(needle.len()).position(|w| w == needle)?;
+    Some((&haystack[..i], &haystack[i + needle.len()..]))
+}
+
+fn trim_crlf(mut b: &[u8]) -> &[u8] {
+    while let Some(rest) = b.strip_prefix(b"\r\n") {
+        b = rest;
+    }
+    while let Some(rest) = b.strip_suffix(b"\r\n") {
+        b = rest;
+    }
+    b
+}
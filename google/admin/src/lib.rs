@@ -111,17 +111,21 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod asps;
+pub mod batch;
 pub mod channels;
 pub mod chromeosdevices;
 pub mod customer;
 pub mod customers;
 pub mod domain_aliases;
 pub mod domains;
+pub mod fields;
 pub mod groups;
 pub mod members;
 pub mod mobiledevices;
 pub mod orgunits;
 pub mod privileges;
+pub mod push;
+pub mod reconcile;
 pub mod resources;
 pub mod role_assignments;
 pub mod roles;
@@ -167,6 +171,22 @@ pub enum ClientError {
     /// utf8 convertion error
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+
+    /// A field mask names a field the response does not have
+    #[error("Invalid field mask: {0}")]
+    InvalidFieldMask(String),
+
+    /// The batch a call was queued in was dropped before it was sent
+    #[error("The batch was not sent")]
+    BatchNotSent,
+
+    /// A watch response does not say which resource the channel is for
+    #[error("{id}: watch response is missing the channel resourceId")]
+    MissingResourceId { id: String },
+
+    /// A desired member is of a group that neither exists nor is desired
+    #[error("{email}: group neither exists nor is desired")]
+    UnknownGroup { email: String },
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
@@ -248,6 +268,8 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
+    batch: Option<crate::batch::Queue>,
+    fields: Option<String>,
 
     auto_refresh: bool,
     #[cfg(feature = "middleware")]
@@ -362,6 +384,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    batch: None,
+                    fields: None,
 
                     auto_refresh: false,
                     client,
@@ -517,6 +541,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    batch: None,
+                    fields: None,
                     auto_refresh: false,
                     client,
                 }
@@ -709,7 +735,18 @@ impl Client {
             }
         }
 
-        let req = self.make_request(&method, uri, message).await?;
+        let mut req = self.make_request(&method, uri, message).await?;
+        if let Some(fields) = &self.fields {
+            // Requests that pick their own fields, such as those of a sync, keep them.
+            if !req.url().query_pairs().any(|(k, _)| k == "fields") {
+                req.url_mut()
+                    .query_pairs_mut()
+                    .append_pair("fields", fields);
+            }
+        }
+        if let Some(batch) = &self.batch {
+            return batch.enqueue(req).await;
+        }
         let resp = self.client.execute(req).await?;
 
         Ok(resp)
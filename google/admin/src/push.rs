@@ -0,0 +1,354 @@
+//! Push notifications for watched resources.
+//!
+//! A watch registers a notification channel, Google then POSTs to the
+//! channel's address whenever the resource changes. Notifications carry no
+//! body, everything is in the `X-Goog-*` headers which [`Notification`] parses.
+//! Channels expire and must be re-created before they do, [`ChannelManager`]
+//! keeps track of the open channels and renews them.
+//!
+//! FROM: <https://developers.google.com/admin-sdk/directory/v1/guides/push>
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::{Client, ClientError, ClientResult, Response, types::Channel};
+
+pub const CHANNEL_ID_HEADER: &str = "X-Goog-Channel-ID";
+pub const CHANNEL_TOKEN_HEADER: &str = "X-Goog-Channel-Token";
+pub const CHANNEL_EXPIRATION_HEADER: &str = "X-Goog-Channel-Expiration";
+pub const MESSAGE_NUMBER_HEADER: &str = "X-Goog-Message-Number";
+pub const RESOURCE_ID_HEADER: &str = "X-Goog-Resource-ID";
+pub const RESOURCE_STATE_HEADER: &str = "X-Goog-Resource-State";
+pub const RESOURCE_URI_HEADER: &str = "X-Goog-Resource-URI";
+pub const CHANGED_HEADER: &str = "X-Goog-Changed";
+
+/// What happened to the watched resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceState {
+    /// Sent once when the channel is created, before any change.
+    Sync,
+    Exists,
+    NotExists,
+    Add,
+    Remove,
+    Update,
+    Trash,
+    Untrash,
+    Change,
+    Delete,
+    Undelete,
+    MakeAdmin,
+    Unknown(String),
+}
+
+impl From<&str> for ResourceState {
+    fn from(s: &str) -> Self {
+        match s {
+            "sync" => ResourceState::Sync,
+            "exists" => ResourceState::Exists,
+            "not_exists" => ResourceState::NotExists,
+            "add" => ResourceState::Add,
+            "remove" => ResourceState::Remove,
+            "update" => ResourceState::Update,
+            "trash" => ResourceState::Trash,
+            "untrash" => ResourceState::Untrash,
+            "change" => ResourceState::Change,
+            "delete" => ResourceState::Delete,
+            "undelete" => ResourceState::Undelete,
+            "makeAdmin" => ResourceState::MakeAdmin,
+            s => ResourceState::Unknown(s.to_string()),
+        }
+    }
+}
+
+/// A push notification, parsed from the headers of the request Google sent.
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub channel_id: String,
+    /// Increases with every message on the channel, `1` for the sync message.
+    pub message_number: u64,
+    pub resource_id: String,
+    pub resource_state: ResourceState,
+    pub resource_uri: String,
+    /// The token the channel was created with, if any.
+    pub channel_token: String,
+    pub channel_expiration: Option<DateTime<Utc>>,
+    /// What changed, for resources that report it.
+    pub changed: Vec<String>,
+}
+
+impl Notification {
+    /// Parse a notification from request headers, so any web framework can be
+    /// used to receive them. Header names are matched case-insensitively.
+    ///
+    /// Returns `None` if the headers are not those of a push notification.
+    pub fn from_headers<'a, I>(headers: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut channel_id = None;
+        let mut message_number = None;
+        let mut resource_id = None;
+        let mut resource_state = None;
+        let mut resource_uri = String::new();
+        let mut channel_token = String::new();
+        let mut channel_expiration = None;
+        let mut changed = Vec::new();
+
+        for (name, value) in headers {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case(CHANNEL_ID_HEADER) {
+                channel_id = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case(MESSAGE_NUMBER_HEADER) {
+                message_number = value.parse().ok();
+            } else if name.eq_ignore_ascii_case(RESOURCE_ID_HEADER) {
+                resource_id = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case(RESOURCE_STATE_HEADER) {
+                resource_state = Some(ResourceState::from(value));
+            } else if name.eq_ignore_ascii_case(RESOURCE_URI_HEADER) {
+                resource_uri = value.to_string();
+            } else if name.eq_ignore_ascii_case(CHANNEL_TOKEN_HEADER) {
+                channel_token = value.to_string();
+            } else if name.eq_ignore_ascii_case(CHANNEL_EXPIRATION_HEADER) {
+                channel_expiration = DateTime::parse_from_rfc2822(value)
+                    .ok()
+                    .map(|d| d.with_timezone(&Utc));
+            } else if name.eq_ignore_ascii_case(CHANGED_HEADER) {
+                changed.extend(
+                    value
+                        .split(',')
+                        .map(|c| c.trim().to_string())
+                        .filter(|c| !c.is_empty()),
+                );
+            }
+        }
+
+        Some(Notification {
+            channel_id: channel_id?,
+            message_number: message_number?,
+            resource_id: resource_id?,
+            resource_state: resource_state?,
+            resource_uri,
+            channel_token,
+            channel_expiration,
+            changed,
+        })
+    }
+
+    /// Parse a notification from an [`http::HeaderMap`].
+    pub fn from_header_map(headers: &http::HeaderMap) -> Option<Self> {
+        Self::from_headers(
+            headers
+                .iter()
+                .filter_map(|(n, v)| Some((n.as_str(), v.to_str().ok()?))),
+        )
+    }
+}
+
+type WatchFuture = Pin<Box<dyn Future<Output = ClientResult<Response<Channel>>> + Send>>;
+type WatchFn = Arc<dyn Fn(Client, Channel) -> WatchFuture + Send + Sync>;
+
+struct Watch {
+    channel: Channel,
+    subscribe: WatchFn,
+}
+
+/// Keeps track of open notification channels and renews them before they
+/// expire.
+///
+/// ```ignore
+/// let mut channels = ChannelManager::new(client, "https://example.com/notifications");
+/// channels
+///     .watch(|client, channel| async move {
+///         client
+///             .users()
+///             .watch(
+///                 "my_customer",
+///                 "",
+///                 Event::Add,
+///                 0,
+///                 DirectoryUsersListOrderBy::Noop,
+///                 "",
+///                 DirectoryUsersListProjection::Noop,
+///                 "",
+///                 "",
+///                 SortOrder::Noop,
+///                 ViewType::Noop,
+///                 &channel,
+///             )
+///             .await
+///     })
+///     .await?;
+///
+/// // Periodically, before `channels.next_renewal()`:
+/// channels.renew().await?;
+/// ```
+pub struct ChannelManager {
+    client: Client,
+    address: String,
+    renew_before: TimeDelta,
+    watches: Vec<Watch>,
+}
+
+impl ChannelManager {
+    /// Create a manager whose channels deliver notifications to `address`,
+    /// which must be an HTTPS URL.
+    pub fn new<A>(client: Client, address: A) -> Self
+    where
+        A: ToString,
+    {
+        ChannelManager {
+            client,
+            address: address.to_string(),
+            renew_before: TimeDelta::hours(1),
+            watches: Vec::new(),
+        }
+    }
+
+    /// How long before a channel expires [`ChannelManager::renew`] re-creates
+    /// it, an hour by default.
+    pub fn with_renew_before(mut self, renew_before: TimeDelta) -> Self {
+        self.renew_before = renew_before;
+        self
+    }
+
+    /// Open a channel. `subscribe` is given the channel to send and calls the
+    /// matching `watch` function, it is called again on every renewal.
+    pub async fn watch<F, Fut>(&mut self, subscribe: F) -> ClientResult<&Channel>
+    where
+        F: Fn(Client, Channel) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ClientResult<Response<Channel>>> + Send + 'static,
+    {
+        let subscribe: WatchFn =
+            Arc::new(move |client, channel| Box::pin(subscribe(client, channel)));
+        let channel = self.subscribe(&subscribe).await?;
+        self.watches.push(Watch { channel, subscribe });
+
+        Ok(&self.watches.last().unwrap().channel)
+    }
+
+    /// The open channels.
+    pub fn channels(&self) -> impl Iterator<Item = &Channel> {
+        self.watches.iter().map(|w| &w.channel)
+    }
+
+    /// Check that a notification is for one of the open channels, comparing the
+    /// channel ID, token and resource ID, and return that channel.
+    ///
+    /// Notifications that do not match should be ignored, they either come
+    /// from a stopped channel or were not sent by Google.
+    pub fn verify(&self, notification: &Notification) -> Option<&Channel> {
+        self.channels().find(|c| {
+            c.id == notification.channel_id
+                && c.token == notification.channel_token
+                && c.resource_id == notification.resource_id
+        })
+    }
+
+    /// When [`ChannelManager::renew`] next has work to do, `None` if no channel
+    /// with a known expiration is open.
+    pub fn next_renewal(&self) -> Option<DateTime<Utc>> {
+        self.channels()
+            .filter(|c| c.expiration > 0)
+            .filter_map(|c| DateTime::from_timestamp_millis(c.expiration))
+            .min()
+            .map(|e| e - self.renew_before)
+    }
+
+    /// Re-create the channels that expire within the renewal window, then stop
+    /// the channels they replace. Channels Google gave no expiration for are
+    /// left as they are. Failing to stop an old channel, which has often
+    /// expired already, is logged rather than returned.
+    ///
+    /// Notifications may arrive on both the old and the new channel for a
+    /// short while, both pass [`ChannelManager::verify`] until the old one is
+    /// stopped.
+    pub async fn renew(&mut self) -> ClientResult<()> {
+        let deadline = now_millis() + self.renew_before.num_milliseconds();
+
+        for i in 0..self.watches.len() {
+            let expiration = self.watches[i].channel.expiration;
+            if expiration == 0 || expiration > deadline {
+                continue;
+            }
+
+            let subscribe = self.watches[i].subscribe.clone();
+            let channel = self.subscribe(&subscribe).await?;
+            let old = std::mem::replace(&mut self.watches[i].channel, channel);
+            // Its replacement is open whether or not this works.
+            if let Err(e) = self.stop_channel(&old).await {
+                log::warn!("failed to stop channel {}: {e}", old.id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stop a channel, notifications for it are no longer sent.
+    pub async fn stop(&mut self, id: &str) -> ClientResult<()> {
+        if let Some(i) = self.watches.iter().position(|w| w.channel.id == id) {
+            let watch = self.watches.remove(i);
+            self.stop_channel(&watch.channel).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Stop every open channel. Channels that cannot be stopped, such as those
+    /// that expired already, are logged and forgotten.
+    pub async fn stop_all(&mut self) -> ClientResult<()> {
+        while let Some(watch) = self.watches.pop() {
+            if let Err(e) = self.stop_channel(&watch.channel).await {
+                log::warn!("failed to stop channel {}: {e}", watch.channel.id);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn subscribe(&self, subscribe: &WatchFn) -> ClientResult<Channel> {
+        let channel: Channel = serde_json::from_value(serde_json::json!({
+            "id": uuid::Uuid::new_v4().to_string(),
+            "token": uuid::Uuid::new_v4().simple().to_string(),
+            "type": "web_hook",
+            "address": self.address,
+        }))?;
+
+        let resp = subscribe(self.client.clone(), channel.clone()).await?;
+        let mut opened = resp.body;
+        // The response does not echo the token back.
+        if opened.token.is_empty() {
+            opened.token = channel.token;
+        }
+        if opened.id.is_empty() {
+            opened.id = channel.id;
+        }
+        if opened.resource_id.is_empty() {
+            return Err(ClientError::MissingResourceId { id: opened.id });
+        }
+
+        Ok(opened)
+    }
+
+    async fn stop_channel(&self, channel: &Channel) -> ClientResult<()> {
+        let body: Channel = serde_json::from_value(serde_json::json!({
+            "id": channel.id,
+            "resourceId": channel.resource_id,
+        }))?;
+        self.client.channels().admin_stop(&body).await?;
+
+        Ok(())
+    }
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
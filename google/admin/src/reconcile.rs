@@ -0,0 +1,636 @@
+//! Reconciliation of users, groups and memberships.
+//!
+//! Given the users, groups and memberships a directory should have, such as
+//! from an HR system, a [`Reconciler`] lists what the directory has, plans the
+//! changes that make it match and applies them. A dry run is a plan that is
+//! looked at rather than applied.
+//!
+//! ```ignore
+//! let reconciler = Reconciler::new(client, "my_customer").removed_users(Removal::Suspend);
+//! let plan = reconciler.plan(&desired).await?;
+//! println!("{plan}");
+//!
+//! for applied in reconciler.apply(plan).await {
+//!     if let Err(e) = applied.result {
+//!         eprintln!("{}: {e}", applied.change);
+//!     }
+//! }
+//! ```
+//!
+//! Only what a desired user, group or membership describes is compared and
+//! changed, anything else about them is left as is.
+//!
+//! FROM: <https://developers.google.com/admin-sdk/directory/v1/guides/manage-users>
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use futures::{StreamExt, stream};
+use serde_json::{Value, json};
+
+use crate::{
+    Client, ClientError, ClientResult,
+    types::{
+        DirectoryGroupsListOrderBy, DirectoryUsersListOrderBy, DirectoryUsersListProjection, Event,
+        Group, Member, SortOrder, User, ViewType,
+    },
+};
+
+/// A user as it should be.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DesiredUser {
+    pub primary_email: String,
+    pub given_name: String,
+    pub family_name: String,
+    /// The organizational unit of the user, such as `/Engineering`, empty to
+    /// leave it as is.
+    pub org_unit_path: String,
+    pub suspended: bool,
+}
+
+/// A group as it should be.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DesiredGroup {
+    pub email: String,
+    pub name: String,
+    pub description: String,
+}
+
+/// The role of a member in a group.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    #[default]
+    Member,
+    Manager,
+    Owner,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Member => "MEMBER",
+            Role::Manager => "MANAGER",
+            Role::Owner => "OWNER",
+        }
+    }
+}
+
+impl From<&str> for Role {
+    fn from(role: &str) -> Self {
+        match role {
+            "MANAGER" => Role::Manager,
+            "OWNER" => Role::Owner,
+            _ => Role::Member,
+        }
+    }
+}
+
+/// A membership as it should be.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DesiredMember {
+    /// The email of the group.
+    pub group: String,
+    /// The email of the member, a user or another group.
+    pub email: String,
+    pub role: Role,
+}
+
+/// What a directory should have.
+///
+/// The members of every group that is in `groups` or that `members` name are
+/// exactly those in `members`, the members of other groups are left as is.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Desired {
+    pub users: Vec<DesiredUser>,
+    pub groups: Vec<DesiredGroup>,
+    pub members: Vec<DesiredMember>,
+}
+
+/// What to do with the users a directory has that are not desired.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Removal {
+    /// Leave them as they are.
+    #[default]
+    Keep,
+    /// Suspend them, they can be restored with their data.
+    Suspend,
+    /// Delete them.
+    Delete,
+}
+
+/// A change to a directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    CreateGroup(DesiredGroup),
+    /// Update the fields of a group that differ, by their JSON names.
+    UpdateGroup {
+        group: DesiredGroup,
+        fields: Vec<&'static str>,
+    },
+    /// Create a user, with a random password they have to change when they
+    /// first sign in.
+    CreateUser(DesiredUser),
+    /// Update the fields of a user that differ, by their JSON names.
+    UpdateUser {
+        user: DesiredUser,
+        fields: Vec<&'static str>,
+    },
+    AddMember(DesiredMember),
+    UpdateMember(DesiredMember),
+    RemoveMember {
+        group: String,
+        email: String,
+    },
+    DeleteGroup(String),
+    SuspendUser(String),
+    DeleteUser(String),
+}
+
+impl Change {
+    /// Changes are applied in phases so that groups and users exist before
+    /// they become members, and are only removed once nothing is added to
+    /// them.
+    fn phase(&self) -> u8 {
+        match self {
+            Change::CreateGroup(_) | Change::UpdateGroup { .. } => 0,
+            Change::CreateUser(_) | Change::UpdateUser { .. } => 1,
+            Change::AddMember(_) | Change::UpdateMember(_) | Change::RemoveMember { .. } => 2,
+            Change::DeleteGroup(_) | Change::SuspendUser(_) | Change::DeleteUser(_) => 3,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::CreateGroup(g) => write!(f, "create group {}", g.email),
+            Change::UpdateGroup { group, fields } => {
+                write!(f, "update group {} ({})", group.email, fields.join(", "))
+            }
+            Change::CreateUser(u) => write!(f, "create user {}", u.primary_email),
+            Change::UpdateUser { user, fields } => {
+                write!(
+                    f,
+                    "update user {} ({})",
+                    user.primary_email,
+                    fields.join(", ")
+                )
+            }
+            Change::AddMember(m) => {
+                write!(f, "add {} to {} as {}", m.email, m.group, m.role.as_str())
+            }
+            Change::UpdateMember(m) => {
+                write!(f, "make {} {} of {}", m.email, m.role.as_str(), m.group)
+            }
+            Change::RemoveMember { group, email } => write!(f, "remove {email} from {group}"),
+            Change::DeleteGroup(email) => write!(f, "delete group {email}"),
+            Change::SuspendUser(email) => write!(f, "suspend user {email}"),
+            Change::DeleteUser(email) => write!(f, "delete user {email}"),
+        }
+    }
+}
+
+/// The changes that make a directory match what is desired, in the order
+/// they are applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Plan {
+    pub changes: Vec<Change>,
+}
+
+impl Plan {
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Whether the directory already matches.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An applied change and how applying it went.
+#[derive(Debug)]
+pub struct Applied {
+    pub change: Change,
+    pub result: ClientResult<()>,
+}
+
+/// Plans and applies changes to the directory of a customer, see the
+/// [module documentation](self).
+#[derive(Clone)]
+pub struct Reconciler {
+    client: Client,
+    customer: String,
+    removed_users: Removal,
+    delete_groups: bool,
+    concurrency: usize,
+}
+
+impl Reconciler {
+    /// A reconciler for the directory of `customer`, `my_customer` for the
+    /// customer of the authenticated user.
+    pub fn new<C: ToString>(client: Client, customer: C) -> Self {
+        Reconciler {
+            client,
+            customer: customer.to_string(),
+            removed_users: Removal::default(),
+            delete_groups: false,
+            concurrency: 4,
+        }
+    }
+
+    /// What to do with users that are not desired, they are kept by default.
+    /// Administrators, delegated ones included, are never suspended or
+    /// deleted.
+    pub fn removed_users(mut self, removal: Removal) -> Self {
+        self.removed_users = removal;
+        self
+    }
+
+    /// Whether to delete groups that are not desired, which is not done by
+    /// default.
+    pub fn delete_groups(mut self, delete: bool) -> Self {
+        self.delete_groups = delete;
+        self
+    }
+
+    /// How many changes are applied at once, 4 by default.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Compare the directory to what is desired.
+    ///
+    /// Fails with [`ClientError::UnknownGroup`] when a desired member is of a
+    /// group that neither exists nor is in `desired.groups`.
+    pub async fn plan(&self, desired: &Desired) -> ClientResult<Plan> {
+        let users = self
+            .client
+            .users()
+            .list_all(
+                &self.customer,
+                "",
+                Event::Noop,
+                DirectoryUsersListOrderBy::Noop,
+                DirectoryUsersListProjection::Noop,
+                "",
+                "",
+                SortOrder::Noop,
+                ViewType::Noop,
+            )
+            .await?
+            .body;
+        let groups = self
+            .client
+            .groups()
+            .list_all(
+                &self.customer,
+                "",
+                DirectoryGroupsListOrderBy::Noop,
+                "",
+                SortOrder::Noop,
+                "",
+            )
+            .await?
+            .body;
+
+        let mut changes = Vec::new();
+        self.plan_users(&desired.users, users, &mut changes);
+        let existing_groups = self.plan_groups(&desired.groups, groups, &mut changes);
+
+        if let Some(member) = desired.members.iter().find(|m| {
+            let group = key(&m.group);
+            !existing_groups.contains(&group)
+                && !desired.groups.iter().any(|g| key(&g.email) == group)
+        }) {
+            return Err(ClientError::UnknownGroup {
+                email: member.group.to_string(),
+            });
+        }
+
+        // The members of the managed groups, by group and then member.
+        let mut wanted: BTreeMap<String, BTreeMap<String, &DesiredMember>> = BTreeMap::new();
+        for group in &desired.groups {
+            wanted.entry(key(&group.email)).or_default();
+        }
+        for member in &desired.members {
+            wanted
+                .entry(key(&member.group))
+                .or_default()
+                .insert(key(&member.email), member);
+        }
+
+        for (group, wanted) in wanted {
+            let members: Vec<Member> = if existing_groups.contains(&group) {
+                self.client
+                    .members()
+                    .list_all(&group, false, "")
+                    .await?
+                    .body
+            } else {
+                Vec::new()
+            };
+            plan_members(&group, &wanted, members, &mut changes);
+        }
+
+        changes.sort_by_key(Change::phase);
+        Ok(Plan { changes })
+    }
+
+    fn plan_users(&self, desired: &[DesiredUser], users: Vec<User>, changes: &mut Vec<Change>) {
+        let mut users: BTreeMap<String, User> = users
+            .into_iter()
+            .map(|u| (key(&u.primary_email), u))
+            .collect();
+
+        for user in desired {
+            let Some(existing) = users.remove(&key(&user.primary_email)) else {
+                changes.push(Change::CreateUser(user.clone()));
+                continue;
+            };
+
+            let (given_name, family_name) = existing
+                .name
+                .map(|n| (n.given_name, n.family_name))
+                .unwrap_or_default();
+            let mut fields = Vec::new();
+            if given_name != user.given_name || family_name != user.family_name {
+                fields.push("name");
+            }
+            if !user.org_unit_path.is_empty() && existing.org_unit_path != user.org_unit_path {
+                fields.push("orgUnitPath");
+            }
+            if existing.suspended != user.suspended {
+                fields.push("suspended");
+            }
+            if !fields.is_empty() {
+                changes.push(Change::UpdateUser {
+                    user: user.clone(),
+                    fields,
+                });
+            }
+        }
+
+        for (_, user) in users {
+            if user.is_admin || user.is_delegated_admin {
+                continue;
+            }
+            match self.removed_users {
+                Removal::Keep => {}
+                Removal::Suspend if !user.suspended => {
+                    changes.push(Change::SuspendUser(user.primary_email))
+                }
+                Removal::Suspend => {}
+                Removal::Delete => changes.push(Change::DeleteUser(user.primary_email)),
+            }
+        }
+    }
+
+    /// Plan the changes to groups and return the emails of those that exist.
+    fn plan_groups(
+        &self,
+        desired: &[DesiredGroup],
+        groups: Vec<Group>,
+        changes: &mut Vec<Change>,
+    ) -> BTreeSet<String> {
+        let existing: BTreeSet<String> = groups.iter().map(|g| key(&g.email)).collect();
+        let mut groups: BTreeMap<String, Group> =
+            groups.into_iter().map(|g| (key(&g.email), g)).collect();
+
+        for group in desired {
+            let Some(existing) = groups.remove(&key(&group.email)) else {
+                changes.push(Change::CreateGroup(group.clone()));
+                continue;
+            };
+
+            let mut fields = Vec::new();
+            if existing.name != group.name {
+                fields.push("name");
+            }
+            if existing.description != group.description {
+                fields.push("description");
+            }
+            if !fields.is_empty() {
+                changes.push(Change::UpdateGroup {
+                    group: group.clone(),
+                    fields,
+                });
+            }
+        }
+
+        if self.delete_groups {
+            changes.extend(groups.into_values().map(|g| Change::DeleteGroup(g.email)));
+        }
+
+        existing
+    }
+
+    /// Apply the changes of a plan, as many at once as the concurrency allows
+    /// and phase by phase. Every change is tried, whether or not the others
+    /// could be applied.
+    pub async fn apply(&self, plan: Plan) -> Vec<Applied> {
+        let mut applied = Vec::with_capacity(plan.changes.len());
+        let mut changes = plan.changes;
+        changes.sort_by_key(Change::phase);
+
+        while !changes.is_empty() {
+            let phase = changes[0].phase();
+            let split = changes
+                .iter()
+                .position(|c| c.phase() != phase)
+                .unwrap_or(changes.len());
+            let rest = changes.split_off(split);
+
+            let mut results: Vec<Applied> = stream::iter(changes)
+                .map(|change| async move {
+                    let result = self.apply_change(&change).await;
+                    Applied { change, result }
+                })
+                .buffer_unordered(self.concurrency)
+                .collect()
+                .await;
+            applied.append(&mut results);
+
+            changes = rest;
+        }
+
+        applied
+    }
+
+    async fn apply_change(&self, change: &Change) -> ClientResult<()> {
+        match change {
+            Change::CreateGroup(group) => {
+                self.send(
+                    http::Method::POST,
+                    "/admin/directory/v1/groups".to_string(),
+                    json!({
+                        "email": group.email,
+                        "name": group.name,
+                        "description": group.description,
+                    }),
+                )
+                .await
+            }
+            Change::UpdateGroup { group, fields } => {
+                let mut body = json!({});
+                for field in fields {
+                    body[*field] = match *field {
+                        "name" => json!(group.name),
+                        _ => json!(group.description),
+                    };
+                }
+                self.send(http::Method::PATCH, path("groups", &group.email), body)
+                    .await
+            }
+            Change::CreateUser(user) => {
+                let mut body = json!({
+                    "primaryEmail": user.primary_email,
+                    "name": { "givenName": user.given_name, "familyName": user.family_name },
+                    "password": uuid::Uuid::new_v4().simple().to_string(),
+                    "changePasswordAtNextLogin": true,
+                    "suspended": user.suspended,
+                });
+                if !user.org_unit_path.is_empty() {
+                    body["orgUnitPath"] = json!(user.org_unit_path);
+                }
+                self.send(
+                    http::Method::POST,
+                    "/admin/directory/v1/users".to_string(),
+                    body,
+                )
+                .await
+            }
+            Change::UpdateUser { user, fields } => {
+                let mut body = json!({});
+                for field in fields {
+                    body[*field] = match *field {
+                        "name" => {
+                            json!({ "givenName": user.given_name, "familyName": user.family_name })
+                        }
+                        "orgUnitPath" => json!(user.org_unit_path),
+                        _ => json!(user.suspended),
+                    };
+                }
+                self.send(
+                    http::Method::PATCH,
+                    path("users", &user.primary_email),
+                    body,
+                )
+                .await
+            }
+            Change::AddMember(member) => {
+                self.send(
+                    http::Method::POST,
+                    format!("{}/members", path("groups", &member.group)),
+                    json!({ "email": member.email, "role": member.role.as_str() }),
+                )
+                .await
+            }
+            Change::UpdateMember(member) => {
+                self.send(
+                    http::Method::PATCH,
+                    format!(
+                        "{}/members/{}",
+                        path("groups", &member.group),
+                        crate::progenitor_support::encode_path(&member.email)
+                    ),
+                    json!({ "role": member.role.as_str() }),
+                )
+                .await
+            }
+            Change::RemoveMember { group, email } => {
+                self.client.members().delete(group, email).await?;
+                Ok(())
+            }
+            Change::DeleteGroup(email) => {
+                self.client.groups().delete(email).await?;
+                Ok(())
+            }
+            Change::SuspendUser(email) => {
+                self.send(
+                    http::Method::PATCH,
+                    path("users", email),
+                    json!({ "suspended": true }),
+                )
+                .await
+            }
+            Change::DeleteUser(email) => {
+                self.client.users().delete(email).await?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Send only the given fields, the generated types would also send the
+    /// defaults of all the others.
+    async fn send(&self, method: http::Method, path: String, body: Value) -> ClientResult<()> {
+        let url = self.client.url(&path, None);
+        let message = crate::Message {
+            body: Some(reqwest::Body::from(serde_json::to_vec(&body)?)),
+            content_type: Some("application/json".to_string()),
+        };
+        let _: crate::Response<Value> = if method == http::Method::POST {
+            self.client.post(&url, message).await?
+        } else {
+            self.client.patch(&url, message).await?
+        };
+        Ok(())
+    }
+}
+
+fn plan_members(
+    group: &str,
+    wanted: &BTreeMap<String, &DesiredMember>,
+    members: Vec<Member>,
+    changes: &mut Vec<Change>,
+) {
+    // Members without an email, such as all the users of a customer, can
+    // not be desired and are left as they are.
+    let mut members: BTreeMap<String, Member> = members
+        .into_iter()
+        .filter(|m| !m.email.is_empty())
+        .map(|m| (key(&m.email), m))
+        .collect();
+
+    for (email, member) in wanted {
+        let desired = DesiredMember {
+            group: group.to_string(),
+            ..(*member).clone()
+        };
+        match members.remove(email) {
+            None => changes.push(Change::AddMember(desired)),
+            Some(existing) if Role::from(existing.role.as_str()) != member.role => {
+                changes.push(Change::UpdateMember(desired))
+            }
+            Some(_) => {}
+        }
+    }
+
+    changes.extend(members.into_values().map(|m| Change::RemoveMember {
+        group: group.to_string(),
+        email: m.email,
+    }));
+}
+
+/// Emails are compared case-insensitively.
+fn key(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+fn path(kind: &str, key: &str) -> String {
+    format!(
+        "/admin/directory/v1/{kind}/{}",
+        crate::progenitor_support::encode_path(key)
+    )
+}
@@ -776,10 +776,10 @@ pub struct Channel {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub params: String,
+    pub params: std::collections::HashMap<String, String>,
     /**
      * An notification channel used to watch for resource changes.
      */
@@ -4090,10 +4090,11 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "customSchemas"
     )]
     pub custom_schemas:
-        std::collections::HashMap<String, std::collections::HashMap<String, serde_json::Value>>,
+        std::collections::HashMap<String, serde_json::Map<String, serde_json::Value>>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
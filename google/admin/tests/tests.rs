@@ -1 +1,393 @@
+use wiremock::{
+    Mock, MockServer, Request, ResponseTemplate,
+    matchers::{body_json, method, path, query_param},
+};
 
+use gsuite_api::{
+    Client, ClientError,
+    fields::FieldMask,
+    push::ChannelManager,
+    reconcile::{
+        Change, Desired, DesiredGroup, DesiredMember, DesiredUser, Reconciler, Removal, Role,
+    },
+    types::{
+        Channel, DirectoryUsersListOrderBy, DirectoryUsersListProjection, Event, SortOrder, User,
+        Users, ViewType,
+    },
+};
+
+#[tokio::test]
+async fn test_field_mask_is_checked_and_sent() {
+    let mask = FieldMask::of::<Users>(&["users/primaryEmail", "users/name/fullName"]).unwrap();
+    assert_eq!(
+        mask.as_str(),
+        "users/primaryEmail,users/name/fullName,nextPageToken"
+    );
+    assert!(matches!(
+        FieldMask::of::<User>(&["primary_email"]),
+        Err(ClientError::InvalidFieldMask(_))
+    ));
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/directory/v1/users"))
+        .and(query_param("fields", mask.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "users": [{ "primaryEmail": "a@example.com", "name": { "fullName": "A" } }],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let users = client
+        .with_fields(&mask)
+        .users()
+        .list_all(
+            "my_customer",
+            "",
+            Event::Noop,
+            DirectoryUsersListOrderBy::Noop,
+            DirectoryUsersListProjection::Noop,
+            "",
+            "",
+            SortOrder::Noop,
+            ViewType::Noop,
+        )
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].primary_email, "a@example.com");
+}
+
+#[tokio::test]
+async fn test_reconcile_plans_and_applies_changes() {
+    let server = MockServer::start().await;
+
+    let list = |p: &str, body: serde_json::Value| {
+        Mock::given(method("GET"))
+            .and(path(p.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+    };
+    list(
+        "/admin/directory/v1/users",
+        serde_json::json!({ "users": [
+            { "primaryEmail": "a@example.com", "name": { "givenName": "Ann", "familyName": "Old" } },
+            { "primaryEmail": "b@example.com", "name": { "givenName": "Bo", "familyName": "B" } },
+            { "primaryEmail": "root@example.com", "isAdmin": true },
+            { "primaryEmail": "help@example.com", "isDelegatedAdmin": true },
+        ] }),
+    )
+    .mount(&server)
+    .await;
+    list(
+        "/admin/directory/v1/groups",
+        serde_json::json!({ "groups": [
+            { "email": "eng@example.com", "name": "Eng" },
+            { "email": "old@example.com", "name": "Old" },
+        ] }),
+    )
+    .mount(&server)
+    .await;
+    list(
+        "/admin/directory/v1/groups/eng@example.com/members",
+        serde_json::json!({ "members": [
+            { "email": "A@example.com", "role": "MEMBER" },
+            { "email": "b@example.com", "role": "MEMBER" },
+            { "type": "CUSTOMER", "role": "MEMBER" },
+        ] }),
+    )
+    .expect(1)
+    .mount(&server)
+    .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+    let reconciler = Reconciler::new(client, "my_customer")
+        .removed_users(Removal::Suspend)
+        .delete_groups(true)
+        .concurrency(2);
+
+    let member = |group: &str, email: &str, role| DesiredMember {
+        group: group.to_string(),
+        email: email.to_string(),
+        role,
+    };
+    let desired = Desired {
+        users: vec![
+            DesiredUser {
+                primary_email: "a@example.com".to_string(),
+                given_name: "Ann".to_string(),
+                family_name: "New".to_string(),
+                ..Default::default()
+            },
+            DesiredUser {
+                primary_email: "c@example.com".to_string(),
+                given_name: "Cy".to_string(),
+                family_name: "C".to_string(),
+                ..Default::default()
+            },
+        ],
+        groups: vec![
+            DesiredGroup {
+                email: "eng@example.com".to_string(),
+                name: "Engineering".to_string(),
+                ..Default::default()
+            },
+            DesiredGroup {
+                email: "new@example.com".to_string(),
+                name: "New".to_string(),
+                ..Default::default()
+            },
+        ],
+        members: vec![
+            member("eng@example.com", "a@example.com", Role::Manager),
+            member("eng@example.com", "c@example.com", Role::Member),
+            member("new@example.com", "c@example.com", Role::Owner),
+        ],
+    };
+
+    let plan = reconciler.plan(&desired).await.unwrap();
+    assert_eq!(
+        plan.to_string(),
+        "update group eng@example.com (name)
+create group new@example.com
+update user a@example.com (name)
+create user c@example.com
+make a@example.com MANAGER of eng@example.com
+add c@example.com to eng@example.com as MEMBER
+remove b@example.com from eng@example.com
+add c@example.com to new@example.com as OWNER
+suspend user b@example.com
+delete group old@example.com
+"
+    );
+    assert!(matches!(&plan.changes[2], Change::UpdateUser { fields, .. } if fields == &["name"]));
+
+    let ok = || ResponseTemplate::new(200).set_body_json(serde_json::json!({}));
+    let mocks = [
+        (
+            "PATCH",
+            "groups/eng@example.com",
+            Some(serde_json::json!({ "name": "Engineering" })),
+        ),
+        ("POST", "groups", None),
+        (
+            "PATCH",
+            "users/a@example.com",
+            Some(serde_json::json!({ "name": { "givenName": "Ann", "familyName": "New" } })),
+        ),
+        ("POST", "users", None),
+        (
+            "PATCH",
+            "groups/eng@example.com/members/a@example.com",
+            Some(serde_json::json!({ "role": "MANAGER" })),
+        ),
+        ("POST", "groups/eng@example.com/members", None),
+        (
+            "DELETE",
+            "groups/eng@example.com/members/b@example.com",
+            None,
+        ),
+        (
+            "POST",
+            "groups/new@example.com/members",
+            Some(serde_json::json!({ "email": "c@example.com", "role": "OWNER" })),
+        ),
+        (
+            "PATCH",
+            "users/b@example.com",
+            Some(serde_json::json!({ "suspended": true })),
+        ),
+        ("DELETE", "groups/old@example.com", None),
+    ];
+    for (m, p, body) in mocks {
+        let mock = Mock::given(method(m)).and(path(format!("/admin/directory/v1/{p}")));
+        let mock = match body {
+            Some(body) => mock.and(body_json(body)),
+            None => mock,
+        };
+        mock.respond_with(ok()).expect(1).mount(&server).await;
+    }
+
+    let applied = reconciler.apply(plan.clone()).await;
+    assert_eq!(applied.len(), plan.len());
+    for applied in applied {
+        assert!(
+            applied.result.is_ok(),
+            "{}: {:?}",
+            applied.change,
+            applied.result
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_reconcile_keeps_users_and_rejects_unknown_groups() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/directory/v1/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "users": [{ "primaryEmail": "b@example.com" }],
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/admin/directory/v1/groups"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "groups": [{ "email": "eng@example.com", "name": "Eng" }],
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/admin/directory/v1/groups/eng@example.com/members"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "members": [{ "email": "b@example.com", "role": "MEMBER" }],
+        })))
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+    let reconciler = Reconciler::new(client, "my_customer");
+
+    let member = |group: &str| DesiredMember {
+        group: group.to_string(),
+        email: "b@example.com".to_string(),
+        role: Role::Member,
+    };
+
+    // Groups that exist can be managed without being desired, undesired
+    // users are kept by default.
+    let plan = reconciler
+        .plan(&Desired {
+            members: vec![member("eng@example.com")],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(plan.is_empty());
+
+    let err = reconciler
+        .plan(&Desired {
+            members: vec![member("typo@example.com")],
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::UnknownGroup { email } if email == "typo@example.com"));
+}
+
+async fn watch_users(manager: &mut ChannelManager) -> Result<Channel, ClientError> {
+    manager
+        .watch(|client, channel| async move {
+            client
+                .users()
+                .watch(
+                    "my_customer",
+                    "",
+                    Event::Add,
+                    0,
+                    DirectoryUsersListOrderBy::Noop,
+                    "",
+                    DirectoryUsersListProjection::Noop,
+                    "",
+                    "",
+                    SortOrder::Noop,
+                    ViewType::Noop,
+                    &channel,
+                )
+                .await
+        })
+        .await
+        .cloned()
+}
+
+#[tokio::test]
+async fn test_channel_manager_renews_and_stops() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/admin/directory/v1/users/watch"))
+        .respond_with(|req: &Request| {
+            let channel: serde_json::Value = req.body_json().unwrap();
+            let expiration = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis()
+                + 10 * 60 * 1000;
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "kind": "api#channel",
+                "id": channel["id"],
+                "resourceId": "users-resource",
+                "expiration": expiration.to_string(),
+            }))
+        })
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/admin/directory_v1/channels/stop"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let mut channels = ChannelManager::new(client, "https://example.com/notifications");
+    let channel = watch_users(&mut channels).await.unwrap();
+    assert_eq!(channel.resource_id, "users-resource");
+
+    // The channel expires within the default renewal window.
+    channels.renew().await.unwrap();
+    let renewed = channels.channels().next().unwrap().clone();
+    assert_ne!(renewed.id, channel.id);
+
+    channels.stop_all().await.unwrap();
+    assert!(channels.channels().next().is_none());
+}
+
+#[tokio::test]
+async fn test_channel_manager_keeps_channels_without_an_expiration() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/admin/directory/v1/users/watch"))
+        .respond_with(|req: &Request| {
+            let channel: serde_json::Value = req.body_json().unwrap();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "kind": "api#channel",
+                "id": channel["id"],
+                "resourceId": "users-resource",
+            }))
+        })
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/admin/directory_v1/channels/stop"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let mut channels = ChannelManager::new(client, "https://example.com/notifications");
+    let channel = watch_users(&mut channels).await.unwrap();
+    assert_eq!(channel.expiration, 0);
+    assert!(channels.next_renewal().is_none());
+
+    channels.renew().await.unwrap();
+    assert_eq!(channels.channels().next().unwrap().id, channel.id);
+}
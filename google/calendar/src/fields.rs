@@ -0,0 +1,130 @@
+//! Partial responses.
+//!
+//! The `fields` parameter limits a response to the fields it names, which
+//! makes a big difference for large resources and long lists. A [`FieldMask`]
+//! is checked against the JSON schema of the response type, so a typo or a
+//! renamed field is caught before the request is sent rather than silently
+//! leaving the field empty.
+//!
+//! ```ignore
+//! let mask = FieldMask::of::<Events>(&["items/id", "items/summary", "items/updated"])?;
+//! let events = client.with_fields(&mask).events().list_all(...).await?;
+//! ```
+//!
+//! FROM: <https://developers.google.com/calendar/api/guides/performance#partial-response>
+use schemars::{
+    JsonSchema, Map,
+    schema::{Schema, SchemaObject, SingleOrVec},
+};
+
+use crate::{Client, ClientError, ClientResult};
+
+/// The fields that carry where to resume listing or syncing from.
+const TOKENS: [&str; 3] = ["nextPageToken", "nextSyncToken", "newStartPageToken"];
+
+/// The fields to return in a response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldMask(String);
+
+impl FieldMask {
+    /// A mask of fields of `T`, the type of the response, by their JSON names.
+    /// Nested fields are separated by `/`, fields of the items of a list are
+    /// nested under the list.
+    ///
+    /// The page and sync tokens, `nextPageToken`, `nextSyncToken` and
+    /// `newStartPageToken`, are always included when `T` has them so that the
+    /// `*_all` functions keep paginating and syncs keep their checkpoint.
+    pub fn of<T: JsonSchema>(fields: &[&str]) -> ClientResult<Self> {
+        let root = schemars::schema_for!(T);
+        let definitions = &root.definitions;
+        let schema = Schema::Object(root.schema);
+
+        let mut mask: Vec<String> = Vec::new();
+        for field in fields {
+            let mut current = &schema;
+            for name in field.split('/') {
+                current = property(current, definitions, name).ok_or_else(|| {
+                    ClientError::InvalidFieldMask(format!(
+                        "{} has no field {field:?}",
+                        T::schema_name()
+                    ))
+                })?;
+            }
+            mask.push(field.to_string());
+        }
+
+        for token in TOKENS {
+            if property(&schema, definitions, token).is_some() && !mask.iter().any(|f| f == token) {
+                mask.push(token.to_string());
+            }
+        }
+
+        Ok(FieldMask(mask.join(",")))
+    }
+
+    /// A mask that is not checked, in the `fields` syntax Google documents.
+    pub fn raw<S: ToString>(fields: S) -> Self {
+        FieldMask(fields.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for FieldMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Client {
+    /// A client that only asks for the fields in `mask` in every request it
+    /// makes, `self` is left as is. Requests that already ask for their own
+    /// fields, such as those of a sync, are sent unchanged.
+    pub fn with_fields(&self, mask: &FieldMask) -> Client {
+        let mut client = self.clone();
+        client.fields = Some(mask.to_string());
+        client
+    }
+}
+
+/// Find a property of a schema, looking through references, optional values
+/// and the items of arrays.
+fn property<'a>(
+    schema: &'a Schema,
+    definitions: &'a Map<String, Schema>,
+    name: &str,
+) -> Option<&'a Schema> {
+    let Schema::Object(schema) = schema else {
+        return None;
+    };
+    let SchemaObject {
+        reference,
+        subschemas,
+        array,
+        object,
+        ..
+    } = schema;
+
+    if let Some(reference) = reference {
+        let definition = reference.rsplit('/').next()?;
+        return property(definitions.get(definition)?, definitions, name);
+    }
+    if let Some(found) = object.as_ref().and_then(|o| o.properties.get(name)) {
+        return Some(found);
+    }
+    if let Some(items) = array.as_ref().and_then(|a| a.items.as_ref()) {
+        return match items {
+            SingleOrVec::Single(items) => property(items, definitions, name),
+            SingleOrVec::Vec(items) => items.iter().find_map(|i| property(i, definitions, name)),
+        };
+    }
+
+    let subschemas = subschemas.as_ref()?;
+    [&subschemas.all_of, &subschemas.any_of, &subschemas.one_of]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .find_map(|s| property(s, definitions, name))
+}
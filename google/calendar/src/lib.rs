@@ -111,13 +111,17 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod acl;
+pub mod batch;
 pub mod calendar_list;
 pub mod calendars;
 pub mod channels;
 pub mod colors;
 pub mod events;
+pub mod fields;
 pub mod freebusy;
+pub mod push;
 pub mod settings;
+pub mod sync;
 pub mod types;
 #[doc(hidden)]
 pub mod utils;
@@ -155,6 +159,18 @@ pub enum ClientError {
     /// utf8 convertion error
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+
+    /// A field mask names a field the response does not have
+    #[error("Invalid field mask: {0}")]
+    InvalidFieldMask(String),
+
+    /// The batch a call was queued in was dropped before it was sent
+    #[error("The batch was not sent")]
+    BatchNotSent,
+
+    /// A watch response does not say which resource the channel is for
+    #[error("{id}: watch response is missing the channel resourceId")]
+    MissingResourceId { id: String },
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
@@ -236,6 +252,8 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
+    batch: Option<crate::batch::Queue>,
+    fields: Option<String>,
 
     auto_refresh: bool,
     #[cfg(feature = "middleware")]
@@ -350,6 +368,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    batch: None,
+                    fields: None,
 
                     auto_refresh: false,
                     client,
@@ -505,6 +525,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    batch: None,
+                    fields: None,
                     auto_refresh: false,
                     client,
                 }
@@ -697,7 +719,18 @@ impl Client {
             }
         }
 
-        let req = self.make_request(&method, uri, message).await?;
+        let mut req = self.make_request(&method, uri, message).await?;
+        if let Some(fields) = &self.fields {
+            // Requests that pick their own fields, such as those of a sync, keep them.
+            if !req.url().query_pairs().any(|(k, _)| k == "fields") {
+                req.url_mut()
+                    .query_pairs_mut()
+                    .append_pair("fields", fields);
+            }
+        }
+        if let Some(batch) = &self.batch {
+            return batch.enqueue(req).await;
+        }
         let resp = self.client.execute(req).await?;
 
         Ok(resp)
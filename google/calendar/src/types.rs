@@ -502,10 +502,10 @@ pub struct Channel {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub params: String,
+    pub params: std::collections::HashMap<String, String>,
     /**
      * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
@@ -582,13 +582,21 @@ pub struct Colors {
     /**
      * A global palette of calendar colors, mapping from the color ID to its definition. A calendarListEntry resource refers to one of these color IDs in its colorId field. Read-only.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub calendar: Option<ColorDefinition>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub calendar: std::collections::HashMap<String, ColorDefinition>,
     /**
      * A global palette of calendar colors, mapping from the color ID to its definition. A calendarListEntry resource refers to one of these color IDs in its colorId field. Read-only.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub event: Option<ColorDefinition>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub event: std::collections::HashMap<String, ColorDefinition>,
     /**
      * ETag of the collection.
      */
@@ -1011,10 +1019,10 @@ pub struct Gadget {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub preferences: String,
+    pub preferences: std::collections::HashMap<String, String>,
     /**
      * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
      */
@@ -1904,13 +1912,21 @@ pub struct FreeBusyResponse {
     /**
      * List of free/busy information for calendars.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub calendars: Option<FreeBusyCalendar>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub calendars: std::collections::HashMap<String, FreeBusyCalendar>,
     /**
      * Expansion of groups.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub groups: Option<FreeBusyGroup>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub groups: std::collections::HashMap<String, FreeBusyGroup>,
     /**
      * ETag of the collection.
      */
@@ -1 +1,337 @@
+use wiremock::{
+    Mock, MockServer, Request, ResponseTemplate,
+    matchers::{method, path, query_param, query_param_is_missing},
+};
 
+use google_calendar::{
+    Client, ClientError,
+    fields::FieldMask,
+    push::{ChannelManager, Notification, ResourceState},
+    types::{Events, SendUpdates},
+};
+
+fn event(id: &str, status: &str) -> serde_json::Value {
+    serde_json::json!({ "id": id, "status": status })
+}
+
+#[tokio::test]
+async fn test_events_sync_falls_back_to_full_sync_on_gone() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param("syncToken", "expired"))
+        .respond_with(ResponseTemplate::new(410).set_body_json(serde_json::json!({
+            "error": { "code": 410, "message": "Sync token is no longer valid." }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param_is_missing("syncToken"))
+        .and(query_param_is_missing("pageToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [event("a", "confirmed")],
+            "nextPageToken": "page-2",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param("pageToken", "page-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [event("b", "confirmed"), event("c", "cancelled")],
+            "nextSyncToken": "fresh",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let result = client
+        .events()
+        .sync("primary", "expired", false)
+        .await
+        .unwrap()
+        .body;
+    assert!(result.full_resync);
+    assert_eq!(result.next_sync_token, "fresh");
+    let changed: Vec<_> = result.changed.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(changed, vec!["a", "b"]);
+    assert_eq!(result.deleted.len(), 1);
+    assert_eq!(result.deleted[0].id, "c");
+}
+
+#[tokio::test]
+async fn test_events_sync_keeps_its_tokens_under_a_field_mask() {
+    let server = MockServer::start().await;
+
+    let mask = FieldMask::of::<Events>(&["items/id"]).unwrap();
+    assert_eq!(mask.as_str(), "items/id,nextPageToken,nextSyncToken");
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param(
+            "fields",
+            "items/id,nextPageToken,nextSyncToken",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [event("a", "confirmed")],
+            "nextSyncToken": "fresh",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    // Even a mask that leaves the tokens out keeps them for a sync.
+    let result = client
+        .with_fields(&FieldMask::raw("items/id"))
+        .events()
+        .sync("primary", "", false)
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(result.next_sync_token, "fresh");
+}
+
+#[tokio::test]
+async fn test_channel_manager_verifies_and_renews() {
+    let server = MockServer::start().await;
+
+    // Echo the channel back the way Google does, with the expiration as a
+    // string and without the token.
+    Mock::given(method("POST"))
+        .and(path("/calendars/primary/acl/watch"))
+        .respond_with(|req: &Request| {
+            let channel: serde_json::Value = req.body_json().unwrap();
+            let expiration = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis()
+                + 10 * 60 * 1000;
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "kind": "api#channel",
+                "id": channel["id"],
+                "resourceId": "acl-resource",
+                "resourceUri": "https://www.googleapis.com/calendar/v3/calendars/primary/acl",
+                "expiration": expiration.to_string(),
+            }))
+        })
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/channels/stop"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let mut channels = ChannelManager::new(client, "https://example.com/notifications");
+    let channel = channels
+        .watch(|client, channel| async move {
+            client.acl().watch("primary", 0, "", false, &channel).await
+        })
+        .await
+        .unwrap()
+        .clone();
+    assert!(channel.expiration > 0);
+    assert!(!channel.token.is_empty());
+
+    let headers = |id: &str, token: &str| {
+        Notification::from_headers([
+            ("x-goog-channel-id", id),
+            ("x-goog-channel-token", token),
+            ("x-goog-message-number", "1"),
+            ("x-goog-resource-id", "acl-resource"),
+            ("x-goog-resource-state", "sync"),
+            ("x-goog-channel-expiration", "Tue, 19 Nov 2013 01:13:52 GMT"),
+        ])
+        .unwrap()
+    };
+
+    let notification = headers(&channel.id, &channel.token);
+    assert_eq!(notification.resource_state, ResourceState::Sync);
+    assert!(notification.channel_expiration.is_some());
+    assert!(channels.verify(&notification).is_some());
+    assert!(channels.verify(&headers(&channel.id, "forged")).is_none());
+
+    // The channel expires within the default renewal window.
+    channels.renew().await.unwrap();
+    let renewed = channels.channels().next().unwrap().clone();
+    assert_ne!(renewed.id, channel.id);
+    assert!(channels.verify(&notification).is_none());
+    assert!(
+        channels
+            .verify(&headers(&renewed.id, &renewed.token))
+            .is_some()
+    );
+}
+
+#[tokio::test]
+async fn test_channel_manager_keeps_channels_without_an_expiration() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/calendars/primary/acl/watch"))
+        .respond_with(|req: &Request| {
+            let channel: serde_json::Value = req.body_json().unwrap();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "kind": "api#channel",
+                "id": channel["id"],
+                "resourceId": "acl-resource",
+            }))
+        })
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/channels/stop"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let mut channels = ChannelManager::new(client, "https://example.com/notifications");
+    let channel = channels
+        .watch(|client, channel| async move {
+            client.acl().watch("primary", 0, "", false, &channel).await
+        })
+        .await
+        .unwrap()
+        .clone();
+    assert_eq!(channel.expiration, 0);
+    assert!(channels.next_renewal().is_none());
+
+    channels.renew().await.unwrap();
+    assert_eq!(channels.channels().next().unwrap().id, channel.id);
+}
+
+#[tokio::test]
+async fn test_channel_manager_renews_every_channel_when_stopping_fails() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/calendars/primary/acl/watch"))
+        .respond_with(|req: &Request| {
+            let channel: serde_json::Value = req.body_json().unwrap();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "kind": "api#channel",
+                "id": channel["id"],
+                "resourceId": "acl-resource",
+                "expiration": "1",
+            }))
+        })
+        .expect(4)
+        .mount(&server)
+        .await;
+
+    // The old channels have expired already.
+    Mock::given(method("POST"))
+        .and(path("/channels/stop"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "error": { "code": 404, "message": "Channel not found" }
+        })))
+        .expect(4)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let mut channels = ChannelManager::new(client, "https://example.com/notifications");
+    let mut ids = Vec::new();
+    for _ in 0..2 {
+        let channel = channels
+            .watch(|client, channel| async move {
+                client.acl().watch("primary", 0, "", false, &channel).await
+            })
+            .await
+            .unwrap();
+        ids.push(channel.id.clone());
+    }
+
+    channels.renew().await.unwrap();
+    let renewed: Vec<_> = channels.channels().map(|c| c.id.clone()).collect();
+    assert_eq!(renewed.len(), 2);
+    assert!(renewed.iter().all(|id| !ids.contains(id)));
+
+    channels.stop_all().await.unwrap();
+    assert_eq!(channels.channels().count(), 0);
+}
+
+#[tokio::test]
+async fn test_batch_sends_one_multipart_request() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/batch/calendar/v3"))
+        .respond_with(|req: &Request| {
+            let body = String::from_utf8_lossy(&req.body);
+            assert_eq!(body.matches("application/http").count(), 2);
+            assert!(body.contains("GET /calendars/primary/events/a HTTP/1.1"));
+            assert!(body.contains("DELETE /calendars/primary/events/b HTTP/1.1"));
+
+            // Responses do not have to be in the order of the calls.
+            let body = "--batch_xyz\r\n\
+                Content-Type: application/http\r\n\
+                Content-ID: <response-item1>\r\n\r\n\
+                HTTP/1.1 404 Not Found\r\n\
+                Content-Type: application/json\r\n\r\n\
+                {\"error\": {\"code\": 404}}\r\n\
+                --batch_xyz\r\n\
+                Content-Type: application/http\r\n\
+                Content-ID: <response-item0>\r\n\r\n\
+                HTTP/1.1 200 OK\r\n\
+                Content-Type: application/json; charset=UTF-8\r\n\r\n\
+                {\"id\": \"a\", \"status\": \"confirmed\"}\r\n\
+                --batch_xyz--\r\n";
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "multipart/mixed; boundary=batch_xyz")
+                .set_body_string(body)
+        })
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let mut batch = client.batch();
+    let get = batch.queue(|client| async move { client.events().get("primary", "a", 0, "").await });
+    let delete = batch.queue(|client| async move {
+        client
+            .events()
+            .delete("primary", "b", false, SendUpdates::Noop)
+            .await
+    });
+    assert!(get.take().is_none());
+    batch.send().await.unwrap();
+
+    let event = get.take().unwrap().unwrap().body;
+    assert_eq!(event.id, "a");
+    assert!(matches!(
+        delete.take().unwrap(),
+        Err(ClientError::HttpError {
+            status: http::StatusCode::NOT_FOUND,
+            ..
+        })
+    ));
+}
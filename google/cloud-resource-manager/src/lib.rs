@@ -110,7 +110,10 @@
 #![allow(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod batch;
+pub mod fields;
 pub mod folders;
+pub mod long_running;
 pub mod operations;
 pub mod types;
 #[doc(hidden)]
@@ -149,6 +152,24 @@ pub enum ClientError {
     /// utf8 convertion error
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+
+    /// A field mask names a field the response does not have
+    #[error("Invalid field mask: {0}")]
+    InvalidFieldMask(String),
+
+    /// The batch a call was queued in was dropped before it was sent
+    #[error("The batch was not sent")]
+    BatchNotSent,
+
+    /// A long-running operation finished with an error
+    #[error("{name}: operation failed with code {}: {}", .status.code, .status.message)]
+    OperationFailed {
+        name: String,
+        status: crate::types::Status,
+    },
+    /// A long-running operation did not finish in time
+    #[error("{name}: operation timed out")]
+    OperationTimeout { name: String },
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
@@ -230,6 +251,8 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
+    batch: Option<crate::batch::Queue>,
+    fields: Option<String>,
 
     auto_refresh: bool,
     #[cfg(feature = "middleware")]
@@ -344,6 +367,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    batch: None,
+                    fields: None,
 
                     auto_refresh: false,
                     client,
@@ -499,6 +524,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    batch: None,
+                    fields: None,
                     auto_refresh: false,
                     client,
                 }
@@ -691,7 +718,18 @@ impl Client {
             }
         }
 
-        let req = self.make_request(&method, uri, message).await?;
+        let mut req = self.make_request(&method, uri, message).await?;
+        if let Some(fields) = &self.fields {
+            // Requests that pick their own fields, such as those of a sync, keep them.
+            if !req.url().query_pairs().any(|(k, _)| k == "fields") {
+                req.url_mut()
+                    .query_pairs_mut()
+                    .append_pair("fields", fields);
+            }
+        }
+        if let Some(batch) = &self.batch {
+            return batch.enqueue(req).await;
+        }
         let resp = self.client.execute(req).await?;
 
         Ok(resp)
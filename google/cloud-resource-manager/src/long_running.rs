@@ -0,0 +1,114 @@
+//! Long-running operations.
+//!
+//! Creating and moving a folder return an [`Operation`] that is usually not
+//! done yet. [`Operations::wait`] polls it, backing off between polls, until
+//! it is and then returns what it resulted in.
+//!
+//! ```ignore
+//! let operation = client.folders().create("organizations/1234", &folder).await?.body;
+//! let folder: Folder = client
+//!     .operations()
+//!     .wait(operation, &PollOptions::default().with_timeout(Duration::from_secs(60)))
+//!     .await?;
+//! ```
+//!
+//! Dropping the future returned by [`Operations::wait`] stops polling, the
+//! operation itself carries on.
+//!
+//! FROM: <https://cloud.google.com/resource-manager/reference/rest/Shared.Types/Operation>
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+
+use crate::{ClientError, ClientResult, operations::Operations, types::Operation};
+
+/// How to poll an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PollOptions {
+    /// The delay before the first poll.
+    pub initial_delay: Duration,
+    /// The longest delay between two polls.
+    pub max_delay: Duration,
+    /// How much longer each delay is than the one before.
+    pub multiplier: f64,
+    /// How long to wait for the operation, forever if `None`.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        PollOptions {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 1.5,
+            timeout: None,
+        }
+    }
+}
+
+impl PollOptions {
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_backoff(mut self, initial_delay: Duration, max_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self.max_delay = max_delay;
+        self
+    }
+}
+
+impl Operations {
+    /// Poll an operation until it is done and return its response as a `T`,
+    /// such as a `Folder`, or its error as [`ClientError::OperationFailed`].
+    ///
+    /// Returns [`ClientError::OperationTimeout`] if it is still not done once
+    /// the timeout of `options` is up.
+    pub async fn wait<T>(&self, mut operation: Operation, options: &PollOptions) -> ClientResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        let deadline = options
+            .timeout
+            .map(|timeout| tokio::time::Instant::now() + timeout);
+        let mut delay = options.initial_delay;
+
+        while !operation.done {
+            if let Some(deadline) = deadline {
+                let now = tokio::time::Instant::now();
+                if now >= deadline {
+                    return Err(ClientError::OperationTimeout {
+                        name: operation.name,
+                    });
+                }
+                delay = delay.min(deadline - now);
+            }
+
+            tokio::time::sleep(delay).await;
+            delay = delay.mul_f64(options.multiplier).min(options.max_delay);
+            operation = self.get(&operation.name).await?.body;
+        }
+
+        result(operation)
+    }
+}
+
+/// The result of a done operation.
+pub fn result<T: DeserializeOwned>(operation: Operation) -> ClientResult<T> {
+    if let Some(status) = operation.error {
+        return Err(ClientError::OperationFailed {
+            name: operation.name,
+            status,
+        });
+    }
+
+    // Operations that result in nothing have no response, which a `()`
+    // deserializes from.
+    let response = if operation.response.is_empty() {
+        serde_json::Value::Null
+    } else {
+        serde_json::Value::Object(operation.response)
+    };
+    Ok(serde_json::from_value(response)?)
+}
@@ -1 +1,111 @@
+use std::time::Duration;
 
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+use google_cloud_resource_manager::{
+    Client, ClientError,
+    long_running::PollOptions,
+    types::{Folder, Operation},
+};
+
+fn options() -> PollOptions {
+    PollOptions::default().with_backoff(Duration::from_millis(1), Duration::from_millis(5))
+}
+
+#[tokio::test]
+async fn test_wait_polls_operations_until_done() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/folders"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "operations/fc.1",
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/operations/fc.1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "operations/fc.1",
+        })))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/operations/fc.1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "operations/fc.1",
+            "done": true,
+            "response": {
+                "@type": "type.googleapis.com/google.cloud.resourcemanager.v2.Folder",
+                "name": "folders/42",
+                "displayName": "Engineering",
+            },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/operations/fc.2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "operations/fc.2",
+            "done": true,
+            "error": { "code": 9, "message": "Folder name is taken" },
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/operations/fc.3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "operations/fc.3",
+        })))
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+    let operation = |name: &str| -> Operation {
+        serde_json::from_value(serde_json::json!({ "name": name })).unwrap()
+    };
+
+    let created = client
+        .folders()
+        .create(
+            "organizations/1",
+            &serde_json::from_value(serde_json::json!({})).unwrap(),
+        )
+        .await
+        .unwrap()
+        .body;
+    let folder: Folder = client.operations().wait(created, &options()).await.unwrap();
+    assert_eq!(folder.name, "folders/42");
+    assert_eq!(folder.display_name, "Engineering");
+
+    match client
+        .operations()
+        .wait::<Folder>(operation("operations/fc.2"), &options())
+        .await
+    {
+        Err(ClientError::OperationFailed { name, status }) => {
+            assert_eq!(name, "operations/fc.2");
+            assert_eq!(status.code, 9);
+            assert_eq!(status.message, "Folder name is taken");
+        }
+        r => panic!("unexpected result: {r:?}"),
+    }
+
+    assert!(matches!(
+        client
+            .operations()
+            .wait::<Folder>(
+                operation("operations/fc.3"),
+                &options().with_timeout(Duration::from_millis(20))
+            )
+            .await,
+        Err(ClientError::OperationTimeout { .. })
+    ));
+}
@@ -0,0 +1,238 @@
+//! Streaming downloads.
+//!
+//! Files with content are downloaded with `alt=media`. Google Docs, Sheets,
+//! Slides and the other Google Workspace types have no content of their own and
+//! are exported to an [`ExportFormat`] instead.
+//!
+//! FROM: <https://developers.google.com/drive/api/guides/manage-downloads>
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{Client, ClientError, ClientResult, Response, types::File};
+
+const GOOGLE_APPS_PREFIX: &str = "application/vnd.google-apps.";
+
+/// A format Google Workspace files can be exported to.
+///
+/// FROM: <https://developers.google.com/drive/api/guides/ref-export-formats>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportFormat {
+    Pdf,
+    Docx,
+    Xlsx,
+    Pptx,
+    Odt,
+    Ods,
+    Odp,
+    Rtf,
+    Csv,
+    Tsv,
+    PlainText,
+    Html,
+    /// HTML with its images, zipped.
+    Zip,
+    Epub,
+    Markdown,
+    Png,
+    Jpeg,
+    Svg,
+    /// The source of an Apps Script project.
+    AppsScriptJson,
+    /// Any other MIME type.
+    Other(String),
+}
+
+impl ExportFormat {
+    pub fn mime_type(&self) -> &str {
+        match self {
+            ExportFormat::Pdf => "application/pdf",
+            ExportFormat::Docx => {
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+            }
+            ExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+            ExportFormat::Pptx => {
+                "application/vnd.openxmlformats-officedocument.presentationml.presentation"
+            }
+            ExportFormat::Odt => "application/vnd.oasis.opendocument.text",
+            ExportFormat::Ods => "application/vnd.oasis.opendocument.spreadsheet",
+            ExportFormat::Odp => "application/vnd.oasis.opendocument.presentation",
+            ExportFormat::Rtf => "application/rtf",
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Tsv => "text/tab-separated-values",
+            ExportFormat::PlainText => "text/plain",
+            ExportFormat::Html => "text/html",
+            ExportFormat::Zip => "application/zip",
+            ExportFormat::Epub => "application/epub+zip",
+            ExportFormat::Markdown => "text/markdown",
+            ExportFormat::Png => "image/png",
+            ExportFormat::Jpeg => "image/jpeg",
+            ExportFormat::Svg => "image/svg+xml",
+            ExportFormat::AppsScriptJson => "application/vnd.google-apps.script+json",
+            ExportFormat::Other(mime_type) => mime_type,
+        }
+    }
+
+    /// The file extension, without the dot, empty for [`ExportFormat::Other`].
+    pub fn extension(&self) -> &str {
+        match self {
+            ExportFormat::Pdf => "pdf",
+            ExportFormat::Docx => "docx",
+            ExportFormat::Xlsx => "xlsx",
+            ExportFormat::Pptx => "pptx",
+            ExportFormat::Odt => "odt",
+            ExportFormat::Ods => "ods",
+            ExportFormat::Odp => "odp",
+            ExportFormat::Rtf => "rtf",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Tsv => "tsv",
+            ExportFormat::PlainText => "txt",
+            ExportFormat::Html => "html",
+            ExportFormat::Zip => "zip",
+            ExportFormat::Epub => "epub",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Png => "png",
+            ExportFormat::Jpeg => "jpg",
+            ExportFormat::Svg => "svg",
+            ExportFormat::AppsScriptJson => "json",
+            ExportFormat::Other(_) => "",
+        }
+    }
+
+    /// The format a Google Workspace type is exported to when none is given,
+    /// `None` for types that cannot be exported, such as folders and forms.
+    pub fn default_for(mime_type: &str) -> Option<Self> {
+        match mime_type.strip_prefix(GOOGLE_APPS_PREFIX)? {
+            "document" => Some(ExportFormat::Docx),
+            "spreadsheet" => Some(ExportFormat::Xlsx),
+            "presentation" => Some(ExportFormat::Pptx),
+            "drawing" => Some(ExportFormat::Png),
+            "script" => Some(ExportFormat::AppsScriptJson),
+            "jam" => Some(ExportFormat::Pdf),
+            _ => None,
+        }
+    }
+}
+
+impl crate::files::Files {
+    /// Stream the content of a file into `writer`, returning the number of
+    /// bytes written.
+    ///
+    /// Google Workspace files are exported to `format`, or to
+    /// [`ExportFormat::default_for`] their type when `None`, other files are
+    /// downloaded as is and `format` is ignored.
+    ///
+    /// A non-zero `offset` resumes an interrupted download, the first `offset`
+    /// bytes are not written again. Exports are generated on every request, so
+    /// only resume one if the file has not changed since.
+    pub async fn download_to<W>(
+        &self,
+        file: &File,
+        format: Option<ExportFormat>,
+        offset: u64,
+        writer: &mut W,
+    ) -> ClientResult<Response<u64>>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        let id = crate::progenitor_support::encode_path(&file.id);
+        let url = if file.mime_type.starts_with(GOOGLE_APPS_PREFIX) {
+            let format = format
+                .or_else(|| ExportFormat::default_for(&file.mime_type))
+                .ok_or_else(|| ClientError::NotDownloadable {
+                    mime_type: file.mime_type.to_string(),
+                })?;
+            let query_ = serde_urlencoded::to_string([("mimeType", format.mime_type())]).unwrap();
+            self.client
+                .url(&format!("/files/{id}/export?{query_}"), None)
+        } else {
+            self.client.url(
+                &format!("/files/{id}?alt=media&supportsAllDrives=true"),
+                None,
+            )
+        };
+
+        download(&self.client, &url, offset, writer).await
+    }
+
+    /// Stream the content of a file into `writer` by its ID, see
+    /// [`Files::download_to`](crate::files::Files::download_to).
+    pub async fn download_by_id_to<W>(
+        &self,
+        id: &str,
+        format: Option<ExportFormat>,
+        offset: u64,
+        writer: &mut W,
+    ) -> ClientResult<Response<u64>>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        let url = self.client.url(
+            &format!(
+                "/files/{}?supportsAllDrives=true&fields=id,mimeType",
+                crate::progenitor_support::encode_path(id)
+            ),
+            None,
+        );
+        let file: File = self.client.get(&url, crate::Message::default()).await?.body;
+
+        self.download_to(&file, format, offset, writer).await
+    }
+}
+
+async fn download<W>(
+    client: &Client,
+    url: &str,
+    offset: u64,
+    writer: &mut W,
+) -> ClientResult<Response<u64>>
+where
+    W: AsyncWrite + Unpin + Send,
+{
+    if client.auto_refresh && client.is_expired().await == Some(true) {
+        client.refresh_access_token().await?;
+    }
+
+    let mut req = client
+        .make_request(&reqwest::Method::GET, url, crate::Message::default())
+        .await?;
+    if offset > 0 {
+        req.headers_mut().insert(
+            reqwest::header::RANGE,
+            reqwest::header::HeaderValue::from_str(&format!("bytes={offset}-"))?,
+        );
+    }
+    let mut resp = client.client.execute(req).await?;
+
+    let status = resp.status();
+    let headers = resp.headers().clone();
+    // Resuming a download that had already completed.
+    if status == http::StatusCode::RANGE_NOT_SATISFIABLE {
+        return Ok(Response::new(status, headers, 0));
+    }
+    if !status.is_success() {
+        let body = resp.bytes().await?;
+        return Err(ClientError::HttpError {
+            status,
+            headers,
+            error: String::from_utf8_lossy(&body).into(),
+        });
+    }
+
+    // Exports ignore the range and send everything.
+    let mut skip = if status == http::StatusCode::PARTIAL_CONTENT {
+        0
+    } else {
+        offset
+    };
+    let mut written = 0;
+    while let Some(chunk) = resp.chunk().await? {
+        let start = skip.min(chunk.len() as u64) as usize;
+        skip -= start as u64;
+        writer.write_all(&chunk[start..]).await?;
+        written += (chunk.len() - start) as u64;
+    }
+    writer.flush().await?;
+
+    Ok(Response::new(status, headers, written))
+}
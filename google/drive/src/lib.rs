@@ -111,14 +111,19 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod about;
+pub mod batch;
 pub mod changes;
 pub mod channels;
 pub mod comments;
+pub mod download;
 pub mod drives;
+pub mod fields;
 pub mod files;
 pub mod permissions;
+pub mod push;
 pub mod replies;
 pub mod revisions;
+pub mod sync;
 pub mod teamdrives;
 pub mod traits;
 pub mod types;
@@ -159,9 +164,27 @@ pub enum ClientError {
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
 
+    /// A field mask names a field the response does not have
+    #[error("Invalid field mask: {0}")]
+    InvalidFieldMask(String),
+
+    /// The batch a call was queued in was dropped before it was sent
+    #[error("The batch was not sent")]
+    BatchNotSent,
+
+    /// A watch response does not say which resource the channel is for
+    #[error("{id}: watch response is missing the channel resourceId")]
+    MissingResourceId { id: String },
+
     /// Google Drive not found
     #[error("{name:?}: Drive not found")]
     DriveNotFound { name: String },
+    /// The file is of a Google Workspace type that cannot be exported
+    #[error("{mime_type:?}: cannot be downloaded")]
+    NotDownloadable { mime_type: String },
+    /// IO errors from download.rs
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
     /// str convertion error
     #[error(transparent)]
     ToStrError(#[from] reqwest::header::ToStrError),
@@ -246,6 +269,8 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
+    batch: Option<crate::batch::Queue>,
+    fields: Option<String>,
 
     auto_refresh: bool,
     #[cfg(feature = "middleware")]
@@ -360,6 +385,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    batch: None,
+                    fields: None,
 
                     auto_refresh: false,
                     client,
@@ -515,6 +542,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    batch: None,
+                    fields: None,
                     auto_refresh: false,
                     client,
                 }
@@ -707,7 +736,18 @@ impl Client {
             }
         }
 
-        let req = self.make_request(&method, uri, message).await?;
+        let mut req = self.make_request(&method, uri, message).await?;
+        if let Some(fields) = &self.fields {
+            // Requests that pick their own fields, such as those of a sync, keep them.
+            if !req.url().query_pairs().any(|(k, _)| k == "fields") {
+                req.url_mut()
+                    .query_pairs_mut()
+                    .append_pair("fields", fields);
+            }
+        }
+        if let Some(batch) = &self.batch {
+            return batch.enqueue(req).await;
+        }
         let resp = self.client.execute(req).await?;
 
         Ok(resp)
@@ -0,0 +1,218 @@
+//! Incremental sync of the changes feed.
+//!
+//! Syncing starts from a page token from `changes/startPageToken`, listing
+//! changes walks `nextPageToken` until the last page which carries a
+//! `newStartPageToken` to start the next sync from. The [`SyncState`] is what
+//! needs to be persisted between syncs.
+//!
+//! FROM: <https://developers.google.com/drive/api/guides/manage-changes>
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, ClientResult, Response, types::File};
+
+/// The fields needed to classify a change, with every field of the file.
+const FIELDS: &str =
+    "nextPageToken,newStartPageToken,changes(changeType,driveId,fileId,removed,time,file)";
+
+/// Where a sync resumes from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncState {
+    pub page_token: String,
+    /// The shared drive being synced, empty for the user's corpus, which then
+    /// includes the files of every shared drive the user is a member of.
+    #[serde(default)]
+    pub drive_id: String,
+    /// Roughly when the changes before `page_token` were listed, files created
+    /// since are reported as added.
+    pub since: DateTime<Utc>,
+}
+
+/// A change to a file.
+#[derive(Debug, Clone)]
+pub enum FileEvent {
+    /// A file created since the previous sync.
+    Added(File),
+    /// A file that existed before the previous sync and changed since, or that
+    /// was moved in from a place that is not synced.
+    Modified(File),
+    /// A file that was deleted, trashed or that the user lost access to.
+    /// `file` is set when it was trashed.
+    Removed {
+        file_id: String,
+        file: Option<File>,
+        time: Option<DateTime<Utc>>,
+    },
+}
+
+impl FileEvent {
+    pub fn file_id(&self) -> &str {
+        match self {
+            FileEvent::Added(f) | FileEvent::Modified(f) => &f.id,
+            FileEvent::Removed { file_id, .. } => file_id,
+        }
+    }
+}
+
+/// A page of changes.
+#[derive(Debug, Clone)]
+pub struct ChangesPage {
+    pub events: Vec<FileEvent>,
+    /// Persist this once the events are handled, to resume from the next page.
+    pub state: SyncState,
+    /// Set on the last page, `state` then starts the next sync.
+    pub last: bool,
+}
+
+/// The result of a sync.
+#[derive(Debug, Clone)]
+pub struct SyncResult {
+    pub events: Vec<FileEvent>,
+    /// The state to start the next sync from.
+    pub state: SyncState,
+}
+
+impl crate::changes::Changes {
+    /// Start syncing a shared drive, or the user's corpus if `drive_id` is
+    /// empty. Only changes made after this call are returned by syncs.
+    pub async fn start_sync(&self, drive_id: &str) -> ClientResult<Response<SyncState>> {
+        let since = now();
+        let resp = self.get_start_page_token(drive_id, true, false, "").await?;
+
+        Ok(Response::new(
+            resp.status,
+            resp.headers,
+            SyncState {
+                page_token: resp.body.start_page_token,
+                drive_id: drive_id.to_string(),
+                since,
+            },
+        ))
+    }
+
+    /// List every change since `state`.
+    pub async fn sync(&self, state: &SyncState) -> ClientResult<Response<SyncResult>> {
+        let mut events = Vec::new();
+        let mut state = state.clone();
+        loop {
+            let resp = list_page(&self.client, &state).await?;
+            let page = resp.body;
+            events.extend(page.events);
+            state = page.state;
+
+            if page.last {
+                return Ok(Response::new(
+                    resp.status,
+                    resp.headers,
+                    SyncResult { events, state },
+                ));
+            }
+        }
+    }
+
+    /// List every change since `state` a page at a time, the stream ends after
+    /// the last page.
+    pub fn sync_stream(
+        &self,
+        state: SyncState,
+    ) -> impl futures::Stream<Item = ClientResult<ChangesPage>> + Unpin + Send + use<> {
+        let client = self.client.clone();
+        Box::pin(futures::stream::try_unfold(Some(state), move |state| {
+            let client = client.clone();
+            async move {
+                let Some(state) = state else {
+                    return ClientResult::Ok(None);
+                };
+                let page = list_page(&client, &state).await?.body;
+                let next = if page.last {
+                    None
+                } else {
+                    Some(page.state.clone())
+                };
+                Ok(Some((page, next)))
+            }
+        }))
+    }
+}
+
+async fn list_page(client: &Client, state: &SyncState) -> ClientResult<Response<ChangesPage>> {
+    let mut query_args = vec![
+        ("pageToken".to_string(), state.page_token.to_string()),
+        ("pageSize".to_string(), "1000".to_string()),
+        ("includeItemsFromAllDrives".to_string(), "true".to_string()),
+        ("includeRemoved".to_string(), "true".to_string()),
+        ("supportsAllDrives".to_string(), "true".to_string()),
+        ("fields".to_string(), FIELDS.to_string()),
+    ];
+    if !state.drive_id.is_empty() {
+        query_args.push(("driveId".to_string(), state.drive_id.to_string()));
+    }
+    let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    let url = client.url(&format!("/changes?{query_}"), None);
+
+    // Taken before listing so a file created while the page is being listed
+    // is reported as added rather than modified by the next sync.
+    let listed_at = now();
+    let resp: Response<crate::types::ChangeList> =
+        client.get(&url, crate::Message::default()).await?;
+    let list = resp.body;
+
+    let events = list
+        .changes
+        .into_iter()
+        // Changes to shared drives themselves are not file events.
+        .filter(|c| c.change_type != "drive" && !c.file_id.is_empty())
+        .map(|c| classify(c, state.since))
+        .collect();
+
+    let last = list.next_page_token.is_empty() || list.next_page_token == state.page_token;
+    let state = if last {
+        SyncState {
+            page_token: list.new_start_page_token,
+            drive_id: state.drive_id.clone(),
+            since: listed_at,
+        }
+    } else {
+        SyncState {
+            page_token: list.next_page_token,
+            ..state.clone()
+        }
+    };
+
+    Ok(Response::new(
+        resp.status,
+        resp.headers,
+        ChangesPage {
+            events,
+            state,
+            last,
+        },
+    ))
+}
+
+fn classify(change: crate::types::Change, since: DateTime<Utc>) -> FileEvent {
+    match change.file {
+        Some(file) if !change.removed.unwrap_or_default() && !file.trashed.unwrap_or_default() => {
+            if file.created_time.is_some_and(|c| c >= since) {
+                FileEvent::Added(file)
+            } else {
+                FileEvent::Modified(file)
+            }
+        }
+        file => FileEvent::Removed {
+            file_id: change.file_id,
+            file,
+            time: change.time,
+        },
+    }
+}
+
+fn now() -> DateTime<Utc> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|d| DateTime::from_timestamp_millis(d.as_millis() as i64))
+        .unwrap_or_default()
+}
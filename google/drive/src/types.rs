@@ -123,11 +123,11 @@ pub struct About {
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "exportFormats"
     )]
-    pub export_formats: Vec<String>,
+    pub export_formats: std::collections::HashMap<String, Vec<String>>,
     /**
      * Information about the user, the user's Drive, and system capabilities.
      */
@@ -143,11 +143,11 @@ pub struct About {
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "importFormats"
     )]
-    pub import_formats: Vec<String>,
+    pub import_formats: std::collections::HashMap<String, Vec<String>>,
     /**
      * Information about the user, the user's Drive, and system capabilities.
      */
@@ -162,11 +162,11 @@ pub struct About {
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "maxImportSizes"
     )]
-    pub max_import_sizes: i64,
+    pub max_import_sizes: std::collections::HashMap<String, i64>,
     /**
      * Information about the user, the user's Drive, and system capabilities.
      */
@@ -383,10 +383,10 @@ pub struct Channel {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub params: String,
+    pub params: std::collections::HashMap<String, String>,
     /**
      * An notification channel used to watch for resource changes.
      */
@@ -1671,11 +1671,11 @@ pub struct File {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "appProperties"
     )]
-    pub app_properties: String,
+    pub app_properties: std::collections::HashMap<String, String>,
     /**
      * The metadata for a file.
      */
@@ -1752,11 +1752,11 @@ pub struct File {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "exportLinks"
     )]
-    pub export_links: String,
+    pub export_links: std::collections::HashMap<String, String>,
     /**
      * The metadata for a file.
      */
@@ -1994,10 +1994,10 @@ pub struct File {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub properties: String,
+    pub properties: std::collections::HashMap<String, String>,
     /**
      * The metadata for a file.
      */
@@ -2644,11 +2644,11 @@ pub struct Revision {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "exportLinks"
     )]
-    pub export_links: String,
+    pub export_links: std::collections::HashMap<String, String>,
     /**
      * The metadata for a revision to a file.
      */
@@ -1 +1,410 @@
+use futures::TryStreamExt;
+use wiremock::{
+    Mock, MockServer, Request, ResponseTemplate,
+    matchers::{header, method, path, query_param},
+};
 
+use google_drive::{
+    Client, ClientError,
+    batch::MAX_BATCH_SIZE,
+    download::ExportFormat,
+    fields::FieldMask,
+    push::ChannelManager,
+    sync::{FileEvent, SyncState},
+    types::File,
+};
+
+#[tokio::test]
+async fn test_changes_sync_stream_classifies_and_checkpoints() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/changes/startPageToken"))
+        .and(query_param("driveId", "shared"))
+        .and(query_param("supportsAllDrives", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "startPageToken": "100",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(query_param("pageToken", "100"))
+        .and(query_param("driveId", "shared"))
+        .and(query_param("includeItemsFromAllDrives", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "nextPageToken": "101",
+            "changes": [
+                {
+                    "changeType": "file",
+                    "fileId": "new",
+                    "file": { "id": "new", "createdTime": "2999-01-01T00:00:00Z" },
+                },
+                { "changeType": "file", "fileId": "gone", "removed": true },
+            ],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(query_param("pageToken", "101"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "newStartPageToken": "200",
+            "changes": [
+                {
+                    "changeType": "file",
+                    "fileId": "old",
+                    "file": { "id": "old", "createdTime": "2001-01-01T00:00:00Z" },
+                },
+                {
+                    "changeType": "file",
+                    "fileId": "binned",
+                    "file": { "id": "binned", "trashed": true },
+                },
+                { "changeType": "drive", "driveId": "shared" },
+            ],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let state = client.changes().start_sync("shared").await.unwrap().body;
+    assert_eq!(state.page_token, "100");
+
+    let pages: Vec<_> = client
+        .changes()
+        .sync_stream(state.clone())
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(pages.len(), 2);
+
+    assert!(!pages[0].last);
+    assert_eq!(pages[0].state.page_token, "101");
+    assert_eq!(pages[0].state.since, state.since);
+    assert!(matches!(&pages[0].events[0], FileEvent::Added(f) if f.id == "new"));
+    assert!(matches!(
+        &pages[0].events[1],
+        FileEvent::Removed { file_id, file: None, .. } if file_id == "gone"
+    ));
+
+    assert!(pages[1].last);
+    assert_eq!(pages[1].state.page_token, "200");
+    assert_eq!(pages[1].state.drive_id, "shared");
+    assert!(pages[1].state.since >= state.since);
+    let ids: Vec<_> = pages[1].events.iter().map(|e| e.file_id()).collect();
+    assert_eq!(ids, vec!["old", "binned"]);
+    assert!(matches!(&pages[1].events[0], FileEvent::Modified(_)));
+    assert!(matches!(
+        &pages[1].events[1],
+        FileEvent::Removed { file: Some(_), .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_field_mask_leaves_sync_fields_alone() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/files/report"))
+        .and(query_param("fields", "id,name"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "report",
+            "name": "Report",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(|req: &Request| {
+            let fields: Vec<_> = req
+                .url
+                .query_pairs()
+                .filter(|(k, _)| k == "fields")
+                .collect();
+            fields.len() == 1 && fields[0].1 != "id,name"
+        })
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "newStartPageToken": "101",
+            "changes": [],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+    let client = client.with_fields(&FieldMask::of::<File>(&["id", "name"]).unwrap());
+
+    let file = client
+        .files()
+        .get("report", false, "", false, false)
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(file.name, "Report");
+
+    let state = SyncState {
+        page_token: "100".to_string(),
+        drive_id: String::new(),
+        since: chrono::DateTime::default(),
+    };
+    let pages: Vec<_> = client
+        .changes()
+        .sync_stream(state)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].state.page_token, "101");
+}
+
+#[tokio::test]
+async fn test_download_resumes_media_and_exports() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/files/blob"))
+        .and(query_param("alt", "media"))
+        .and(header("range", "bytes=3-"))
+        .respond_with(ResponseTemplate::new(206).set_body_bytes(b"defgh".to_vec()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Exports ignore ranges.
+    Mock::given(method("GET"))
+        .and(path("/files/sheet/export"))
+        .and(query_param("mimeType", "text/csv"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"a,b\n1,2\n".to_vec()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/files/doc"))
+        .and(query_param("fields", "id,mimeType"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "doc",
+            "mimeType": "application/vnd.google-apps.document",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/files/doc/export"))
+        .and(query_param("mimeType", ExportFormat::Docx.mime_type()))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PK".to_vec()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+    let files = client.files();
+
+    let file = |id: &str, mime_type: &str| -> File {
+        serde_json::from_value(serde_json::json!({ "id": id, "mimeType": mime_type })).unwrap()
+    };
+
+    let mut out = Vec::new();
+    let written = files
+        .download_to(&file("blob", "application/octet-stream"), None, 3, &mut out)
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(written, 5);
+    assert_eq!(out, b"defgh");
+
+    let mut out = Vec::new();
+    files
+        .download_to(
+            &file("sheet", "application/vnd.google-apps.spreadsheet"),
+            Some(ExportFormat::Csv),
+            4,
+            &mut out,
+        )
+        .await
+        .unwrap();
+    assert_eq!(out, b"1,2\n");
+
+    let mut out = Vec::new();
+    files
+        .download_by_id_to("doc", None, 0, &mut out)
+        .await
+        .unwrap();
+    assert_eq!(out, b"PK");
+
+    let folder = file("folder", "application/vnd.google-apps.folder");
+    assert!(matches!(
+        files.download_to(&folder, None, 0, &mut Vec::new()).await,
+        Err(ClientError::NotDownloadable { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_channel_manager_renews_and_stops() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/files/report/watch"))
+        .respond_with(|req: &Request| {
+            let channel: serde_json::Value = req.body_json().unwrap();
+            let expiration = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis()
+                + 10 * 60 * 1000;
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "kind": "api#channel",
+                "id": channel["id"],
+                "resourceId": "file-resource",
+                "expiration": expiration.to_string(),
+            }))
+        })
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/channels/stop"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let mut channels = ChannelManager::new(client, "https://example.com/notifications");
+    let channel = channels
+        .watch(|client, channel| async move {
+            client
+                .files()
+                .watch("report", false, "", false, false, &channel)
+                .await
+        })
+        .await
+        .unwrap()
+        .clone();
+    assert_eq!(channel.resource_id, "file-resource");
+    assert!(channels.next_renewal().is_some());
+
+    // The channel expires within the default renewal window.
+    channels.renew().await.unwrap();
+    let renewed = channels.channels().next().unwrap().clone();
+    assert_ne!(renewed.id, channel.id);
+
+    channels.stop(&renewed.id).await.unwrap();
+    assert!(channels.channels().next().is_none());
+}
+
+#[tokio::test]
+async fn test_channel_manager_requires_a_resource_id() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/files/report/watch"))
+        .respond_with(|req: &Request| {
+            let channel: serde_json::Value = req.body_json().unwrap();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "kind": "api#channel",
+                "id": channel["id"],
+            }))
+        })
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let mut channels = ChannelManager::new(client, "https://example.com/notifications");
+    let err = channels
+        .watch(|client, channel| async move {
+            client
+                .files()
+                .watch("report", false, "", false, false, &channel)
+                .await
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::MissingResourceId { .. }));
+    assert!(channels.channels().next().is_none());
+}
+
+#[tokio::test]
+async fn test_batch_splits_large_batches() {
+    let server = MockServer::start().await;
+
+    // Answer every call in the batch with the drive it asked for.
+    Mock::given(method("POST"))
+        .and(path("/batch/drive/v3"))
+        .respond_with(|req: &Request| {
+            let body = String::from_utf8_lossy(&req.body);
+            let mut parts = String::new();
+            for line in body.lines() {
+                if let Some(i) = line.strip_prefix("Content-ID: <item") {
+                    let i = i.trim_end_matches('>');
+                    let id = body
+                        .split(&format!("<item{i}>\r\n\r\nGET /drives/"))
+                        .nth(1)
+                        .and_then(|rest| rest.split(' ').next())
+                        .unwrap();
+                    parts.push_str(&format!(
+                        "--batch_xyz\r\n\
+                        Content-Type: application/http\r\n\
+                        Content-ID: <response-item{i}>\r\n\r\n\
+                        HTTP/1.1 200 OK\r\n\
+                        Content-Type: application/json\r\n\r\n\
+                        {{\"id\": \"{id}\"}}\r\n"
+                    ));
+                }
+            }
+            parts.push_str("--batch_xyz--\r\n");
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "multipart/mixed; boundary=batch_xyz")
+                .set_body_string(parts)
+        })
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let mut batch = client.batch();
+    let calls: Vec<_> = (0..MAX_BATCH_SIZE + 1)
+        .map(|i| {
+            batch.queue(move |client| async move {
+                client.drives().get(&format!("drive{i}"), false).await
+            })
+        })
+        .collect();
+    batch.send().await.unwrap();
+
+    for (i, call) in calls.into_iter().enumerate() {
+        assert_eq!(call.take().unwrap().unwrap().body.id, format!("drive{i}"));
+    }
+
+    let requests = server.received_requests().await.unwrap();
+    let sizes: Vec<_> = requests
+        .iter()
+        .map(|r| {
+            String::from_utf8_lossy(&r.body)
+                .matches("application/http")
+                .count()
+        })
+        .collect();
+    assert_eq!(sizes, vec![MAX_BATCH_SIZE, 1]);
+}
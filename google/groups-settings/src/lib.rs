@@ -110,6 +110,8 @@
 #![allow(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod batch;
+pub mod fields;
 pub mod groups;
 pub mod types;
 #[doc(hidden)]
@@ -148,6 +150,14 @@ pub enum ClientError {
     /// utf8 convertion error
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+
+    /// A field mask names a field the response does not have
+    #[error("Invalid field mask: {0}")]
+    InvalidFieldMask(String),
+
+    /// The batch a call was queued in was dropped before it was sent
+    #[error("The batch was not sent")]
+    BatchNotSent,
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
@@ -229,6 +239,8 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
+    batch: Option<crate::batch::Queue>,
+    fields: Option<String>,
 
     auto_refresh: bool,
     #[cfg(feature = "middleware")]
@@ -343,6 +355,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    batch: None,
+                    fields: None,
 
                     auto_refresh: false,
                     client,
@@ -498,6 +512,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    batch: None,
+                    fields: None,
                     auto_refresh: false,
                     client,
                 }
@@ -690,7 +706,18 @@ impl Client {
             }
         }
 
-        let req = self.make_request(&method, uri, message).await?;
+        let mut req = self.make_request(&method, uri, message).await?;
+        if let Some(fields) = &self.fields {
+            // Requests that pick their own fields, such as those of a sync, keep them.
+            if !req.url().query_pairs().any(|(k, _)| k == "fields") {
+                req.url_mut()
+                    .query_pairs_mut()
+                    .append_pair("fields", fields);
+            }
+        }
+        if let Some(batch) = &self.batch {
+            return batch.enqueue(req).await;
+        }
         let resp = self.client.execute(req).await?;
 
         Ok(resp)
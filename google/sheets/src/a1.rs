@@ -0,0 +1,269 @@
+//! A1 notation.
+//!
+//! Ranges such as `'Q1 Sales'!A2:F` name a sheet and a block of cells, either
+//! end of which may be open: `A:F` are whole columns, `2:5` whole rows and
+//! `A2:F` columns from the second row down.
+//!
+//! FROM: <https://developers.google.com/sheets/api/guides/concepts#cell>
+use std::{fmt, str::FromStr};
+
+use crate::{ClientError, types::GridRange};
+
+/// The last column of a sheet, `ZZZ`.
+const LAST_COLUMN: u32 = 18278;
+
+/// A range in A1 notation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct A1Range {
+    /// The name of the sheet, empty for the first visible sheet.
+    pub sheet: String,
+    /// The first column, 1 for `A`.
+    pub start_column: Option<u32>,
+    /// The first row, starting at 1.
+    pub start_row: Option<u32>,
+    pub end_column: Option<u32>,
+    pub end_row: Option<u32>,
+}
+
+impl A1Range {
+    /// The whole of a sheet.
+    pub fn sheet<S: ToString>(sheet: S) -> Self {
+        A1Range {
+            sheet: sheet.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// A single cell, columns and rows start at 1.
+    pub fn cell(mut self, column: u32, row: u32) -> Self {
+        self.start_column = Some(column);
+        self.end_column = Some(column);
+        self.start_row = Some(row);
+        self.end_row = Some(row);
+        self
+    }
+
+    /// Limit the range to the columns `first` through `last`.
+    pub fn columns(mut self, first: u32, last: u32) -> Self {
+        self.start_column = Some(first);
+        self.end_column = Some(last);
+        self
+    }
+
+    /// Limit the range to the rows `first` through `last`.
+    pub fn rows(mut self, first: u32, last: u32) -> Self {
+        self.start_row = Some(first);
+        self.end_row = Some(last);
+        self
+    }
+
+    /// Limit the range to the rows from `first` down.
+    pub fn rows_from(mut self, first: u32) -> Self {
+        self.start_row = Some(first);
+        self.end_row = None;
+        self
+    }
+
+    /// The first row of the range, which holds the header of a table.
+    pub fn first_row(&self) -> Self {
+        let row = self.start_row.unwrap_or(1);
+        A1Range {
+            start_row: Some(row),
+            end_row: Some(row),
+            ..self.clone()
+        }
+    }
+
+    /// The range without its first row.
+    pub fn without_first_row(&self) -> Self {
+        A1Range {
+            start_row: Some(self.start_row.unwrap_or(1) + 1),
+            ..self.clone()
+        }
+    }
+
+    /// The same cells on the sheet with the ID `sheet_id`, as the zero-based
+    /// and half open indexes of a `GridRange`. The name of the sheet is not
+    /// used, requests to update a spreadsheet only know sheets by their ID.
+    pub fn grid_range(&self, sheet_id: i64) -> GridRange {
+        let start = |i: Option<u32>| i.map(|i| i as i64 - 1).unwrap_or_default();
+        let end = |i: Option<u32>| i.map(|i| i as i64).unwrap_or_default();
+
+        GridRange {
+            sheet_id,
+            start_column_index: start(self.start_column),
+            end_column_index: end(self.end_column),
+            start_row_index: start(self.start_row),
+            end_row_index: end(self.end_row),
+        }
+    }
+}
+
+/// The letters of a column, `A` for 1 and `AA` for 27.
+pub fn column_name(mut column: u32) -> String {
+    let mut name = Vec::new();
+    while column > 0 {
+        column -= 1;
+        name.push(b'A' + (column % 26) as u8);
+        column /= 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+/// The column of its letters, case-insensitively, `None` if they are not
+/// letters or the column is too far.
+pub fn column_index(name: &str) -> Option<u32> {
+    if name.is_empty() {
+        return None;
+    }
+    name.bytes().try_fold(0u32, |column, b| {
+        if !b.is_ascii_alphabetic() {
+            return None;
+        }
+        column
+            .checked_mul(26)?
+            .checked_add((b.to_ascii_uppercase() - b'A') as u32 + 1)
+    })
+}
+
+/// Whether a sheet name has to be quoted: anything but letters, digits and
+/// underscores, or a name that could be read as a cell or column.
+fn needs_quotes(sheet: &str) -> bool {
+    !sheet
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+        || sheet.starts_with(|c: char| c.is_ascii_digit())
+        || parse_ref(sheet).is_some()
+}
+
+impl fmt::Display for A1Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut start = (self.start_column, self.start_row);
+        let mut end = (self.end_column, self.end_row);
+        let has_cells = start != (None, None) || end != (None, None);
+
+        if needs_quotes(&self.sheet) && !self.sheet.is_empty() {
+            write!(f, "'{}'", self.sheet.replace('\'', "''"))?;
+        } else {
+            f.write_str(&self.sheet)?;
+        }
+        if !has_cells {
+            return Ok(());
+        }
+        if !self.sheet.is_empty() {
+            f.write_str("!")?;
+        }
+
+        // Neither end of a range can be left out entirely, an open end reaches
+        // the last column and, since no row is given, the last row.
+        if end == (None, None) {
+            start.0 = start.0.or(Some(1));
+            end = (Some(LAST_COLUMN), None);
+        } else if start == (None, None) {
+            start = (end.0.map(|_| 1), end.1.map(|_| 1));
+        }
+
+        let write_ref = |f: &mut fmt::Formatter<'_>, (column, row): (Option<u32>, Option<u32>)| {
+            if let Some(column) = column {
+                f.write_str(&column_name(column))?;
+            }
+            if let Some(row) = row {
+                write!(f, "{row}")?;
+            }
+            Ok(())
+        };
+
+        write_ref(f, start)?;
+        if start != end || start.0.is_none() || start.1.is_none() {
+            f.write_str(":")?;
+            write_ref(f, end)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a cell reference such as `A2`, `A` or `2`.
+fn parse_ref(r: &str) -> Option<(Option<u32>, Option<u32>)> {
+    let split = r
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(r.len());
+    let (letters, digits) = r.split_at(split);
+    // Sheets have at most `LAST_COLUMN` columns, `ZZZ`.
+    if (letters.is_empty() && digits.is_empty()) || letters.len() > 3 {
+        return None;
+    }
+
+    let column = if letters.is_empty() {
+        None
+    } else {
+        Some(column_index(letters)?)
+    };
+    let row = if digits.is_empty() {
+        None
+    } else {
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Some(digits.parse().ok().filter(|r| *r > 0)?)
+    };
+    Some((column, row))
+}
+
+impl FromStr for A1Range {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ClientError::InvalidRange(s.to_string());
+
+        let (sheet, cells) = if let Some(quoted) = s.strip_prefix('\'') {
+            // Quotes in a quoted name are doubled.
+            let mut sheet = String::new();
+            let mut chars = quoted.char_indices().peekable();
+            let rest = loop {
+                match chars.next().ok_or_else(invalid)? {
+                    (i, '\'') if chars.peek().map(|(_, c)| *c) != Some('\'') => {
+                        break &quoted[i + 1..];
+                    }
+                    (_, '\'') => {
+                        chars.next();
+                        sheet.push('\'');
+                    }
+                    (_, c) => sheet.push(c),
+                }
+            };
+            match rest.strip_prefix('!') {
+                Some(cells) => (sheet, cells),
+                None if rest.is_empty() => (sheet, ""),
+                None => return Err(invalid()),
+            }
+        } else {
+            match s.rsplit_once('!') {
+                Some((sheet, cells)) => (sheet.to_string(), cells),
+                // A bare name is a sheet, unless it is a range of cells.
+                None if s.split(':').all(|r| parse_ref(r).is_some()) => (String::new(), s),
+                None => (s.to_string(), ""),
+            }
+        };
+
+        let mut range = A1Range::sheet(sheet);
+        if cells.is_empty() {
+            return Ok(range);
+        }
+
+        let (start, end) = match cells.split_once(':') {
+            Some((start, end)) => (
+                parse_ref(start).ok_or_else(invalid)?,
+                parse_ref(end).ok_or_else(invalid)?,
+            ),
+            None => {
+                let cell = parse_ref(cells).ok_or_else(invalid)?;
+                (cell, cell)
+            }
+        };
+        (range.start_column, range.start_row) = start;
+        (range.end_column, range.end_row) = end;
+
+        Ok(range)
+    }
+}
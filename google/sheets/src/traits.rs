@@ -1,4 +1,4 @@
-use crate::{ClientResult, Response};
+use crate::{ClientError, ClientResult, Response, a1::A1Range};
 
 #[async_trait::async_trait]
 pub trait SpreadsheetOps {
@@ -6,6 +6,40 @@ pub trait SpreadsheetOps {
     /// The `cell_name` is something like `A1` and what is returned is a string representation of
     /// the cell's value.
     async fn cell_get(&self, sheet_id: &str, cell_name: &str) -> ClientResult<Response<String>>;
+
+    /// Read a range as a table, the first row is the header and names the fields
+    /// the other rows are deserialized into. Empty rows are skipped and empty
+    /// cells are `None` for optional fields.
+    ///
+    /// Cells are read as they are displayed and parsed into the type of their
+    /// field, so a number formatted with a currency or thousands separators
+    /// can only be read into a `String`.
+    async fn rows_get<T>(&self, sheet_id: &str, range: &A1Range) -> ClientResult<Response<Vec<T>>>
+    where
+        T: serde::de::DeserializeOwned + Send;
+
+    /// Write `rows` to a range, a header row with the names of their fields
+    /// first, then a row for each. Numbers and booleans are entered as such,
+    /// strings are never parsed as numbers or formulas.
+    async fn rows_update<T>(
+        &self,
+        sheet_id: &str,
+        range: &A1Range,
+        rows: &[T],
+    ) -> ClientResult<Response<crate::types::UpdateValuesResponse>>
+    where
+        T: serde::Serialize + Sync;
+
+    /// Append `rows` after the table in a range, in the order of the columns of
+    /// its header row, which is written first if the range is empty.
+    async fn rows_append<T>(
+        &self,
+        sheet_id: &str,
+        range: &A1Range,
+        rows: &[T],
+    ) -> ClientResult<Response<crate::types::AppendValuesResponse>>
+    where
+        T: serde::Serialize + Sync;
 }
 
 #[async_trait::async_trait]
@@ -32,4 +66,316 @@ impl SpreadsheetOps for crate::spreadsheets::Spreadsheets {
 
         Ok(Response::new(resp.status, resp.headers, String::new()))
     }
+
+    /// Read a range as a table, the first row is the header and names the fields
+    /// the other rows are deserialized into. Empty rows are skipped and empty
+    /// cells are `None` for optional fields.
+    ///
+    /// Cells are read as they are displayed and parsed into the type of their
+    /// field, so a number formatted with a currency or thousands separators
+    /// can only be read into a `String`.
+    async fn rows_get<T>(&self, sheet_id: &str, range: &A1Range) -> ClientResult<Response<Vec<T>>>
+    where
+        T: serde::de::DeserializeOwned + Send,
+    {
+        let resp = self.values(sheet_id, range).await?;
+        let mut values = resp.body.into_iter();
+        let header = values.next().unwrap_or_default();
+
+        let mut rows = Vec::new();
+        for row in values {
+            if row.iter().all(|c| c.is_empty()) {
+                continue;
+            }
+
+            let mut cells = row.into_iter();
+            let fields = header.iter().filter_map(|name| {
+                let cell = Cell(cells.next().unwrap_or_default());
+                (!name.is_empty()).then_some((name.as_str(), cell))
+            });
+            rows.push(T::deserialize(serde::de::value::MapDeserializer::<
+                _,
+                serde_json::Error,
+            >::new(fields))?);
+        }
+
+        Ok(Response::new(resp.status, resp.headers, rows))
+    }
+
+    /// Write `rows` to a range, a header row with the names of their fields
+    /// first, then a row for each. Numbers and booleans are entered as such,
+    /// strings are never parsed as numbers or formulas.
+    async fn rows_update<T>(
+        &self,
+        sheet_id: &str,
+        range: &A1Range,
+        rows: &[T],
+    ) -> ClientResult<Response<crate::types::UpdateValuesResponse>>
+    where
+        T: serde::Serialize + Sync,
+    {
+        let rows = rows.iter().map(to_row).collect::<ClientResult<Vec<_>>>()?;
+        let header: Vec<String> = rows
+            .first()
+            .map(|r| r.iter().map(|(name, _)| name.to_string()).collect())
+            .unwrap_or_default();
+
+        let mut values = vec![header.iter().map(|h| to_cell(h.as_str().into())).collect()];
+        values.extend(
+            rows.into_iter()
+                .map(|r| r.into_iter().map(|(_, v)| to_cell(v)).collect()),
+        );
+
+        self.values_update(
+            sheet_id,
+            &encode_range(range),
+            false,
+            crate::types::DateTimeRenderOption::Noop,
+            crate::types::ValueRenderOption::Noop,
+            crate::types::ValueInputOption::UserEntered,
+            &value_range(range, values),
+        )
+        .await
+    }
+
+    /// Append `rows` after the table in a range, in the order of the columns of
+    /// its header row, which is written first if the range is empty.
+    async fn rows_append<T>(
+        &self,
+        sheet_id: &str,
+        range: &A1Range,
+        rows: &[T],
+    ) -> ClientResult<Response<crate::types::AppendValuesResponse>>
+    where
+        T: serde::Serialize + Sync,
+    {
+        let rows = rows.iter().map(to_row).collect::<ClientResult<Vec<_>>>()?;
+        let mut header = self
+            .values(sheet_id, &range.first_row())
+            .await?
+            .body
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+
+        let mut values = Vec::new();
+        if header.iter().all(|h| h.is_empty()) {
+            header = rows
+                .first()
+                .map(|r| r.iter().map(|(name, _)| name.to_string()).collect())
+                .unwrap_or_default();
+            values.push(header.iter().map(|h| to_cell(h.as_str().into())).collect());
+        }
+
+        for row in rows {
+            let mut cells = vec![String::new(); header.len()];
+            for (name, value) in row {
+                let column = header.iter().position(|h| *h == name).ok_or_else(|| {
+                    ClientError::InvalidRange(format!(
+                        "the header row of {range} has no column {name:?}"
+                    ))
+                })?;
+                cells[column] = to_cell(value);
+            }
+            values.push(cells);
+        }
+
+        self.values_append(
+            sheet_id,
+            &encode_range(range),
+            false,
+            crate::types::InsertDataOption::InsertRows,
+            crate::types::DateTimeRenderOption::Noop,
+            crate::types::ValueRenderOption::Noop,
+            crate::types::ValueInputOption::UserEntered,
+            &value_range(range, values),
+        )
+        .await
+    }
+}
+
+impl crate::spreadsheets::Spreadsheets {
+    /// The values of a range, as they are displayed.
+    async fn values(
+        &self,
+        sheet_id: &str,
+        range: &A1Range,
+    ) -> ClientResult<Response<Vec<Vec<String>>>> {
+        let resp = self
+            .values_get(
+                sheet_id,
+                &encode_range(range),
+                crate::types::DateTimeRenderOption::FormattedString,
+                crate::types::Dimension::Rows,
+                crate::types::ValueRenderOption::FormattedValue,
+            )
+            .await?;
+
+        Ok(Response::new(resp.status, resp.headers, resp.body.values))
+    }
+}
+
+/// Sheet names can hold any character, including `/` and `%` which the
+/// generated functions leave as they are, so encode all of them.
+fn encode_range(range: &A1Range) -> String {
+    percent_encoding::utf8_percent_encode(&range.to_string(), percent_encoding::NON_ALPHANUMERIC)
+        .to_string()
+}
+
+fn value_range(range: &A1Range, values: Vec<Vec<String>>) -> crate::types::ValueRange {
+    crate::types::ValueRange {
+        major_dimension: Some(crate::types::Dimension::Rows),
+        range: range.to_string(),
+        values,
+    }
+}
+
+/// The fields of a value in the order they are declared, which a
+/// `serde_json::Map` does not keep.
+fn to_row<T: serde::Serialize>(value: &T) -> ClientResult<Vec<(String, serde_json::Value)>> {
+    struct Row(Vec<(String, serde_json::Value)>);
+
+    impl<'de> serde::Deserialize<'de> for Row {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct RowVisitor;
+
+            impl<'de> serde::de::Visitor<'de> for RowVisitor {
+                type Value = Row;
+
+                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    formatter.write_str("a struct or map")
+                }
+
+                fn visit_map<A: serde::de::MapAccess<'de>>(
+                    self,
+                    mut map: A,
+                ) -> Result<Self::Value, A::Error> {
+                    let mut row = Vec::new();
+                    while let Some(entry) = map.next_entry()? {
+                        row.push(entry);
+                    }
+                    Ok(Row(row))
+                }
+            }
+
+            deserializer.deserialize_map(RowVisitor)
+        }
+    }
+
+    let Row(row) = serde_json::from_str(&serde_json::to_string(value)?)?;
+    // Cells hold a single value, anything else is written as JSON.
+    Ok(row
+        .into_iter()
+        .map(|(name, value)| match value {
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+                (name, serde_json::Value::String(value.to_string()))
+            }
+            value => (name, value),
+        })
+        .collect())
+}
+
+/// A value as it is entered into a cell. Strings start with a quote so that
+/// they are kept as text rather than parsed as numbers, dates or formulas,
+/// the quote is not part of the value.
+fn to_cell(value: serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) if s.is_empty() => s,
+        serde_json::Value::String(s) => format!("'{s}"),
+        serde_json::Value::Bool(b) => b.to_string().to_uppercase(),
+        v => v.to_string(),
+    }
+}
+
+/// A cell, which deserializes leniently: numbers and booleans are parsed from
+/// its text, since what a cell holds depends on what was typed into it.
+struct Cell(String);
+
+impl serde::de::IntoDeserializer<'_, serde_json::Error> for Cell {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! parse_number {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.0.trim().parse() {
+                    Ok(n) => visitor.$visit(n),
+                    Err(_) => Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Str(&self.0),
+                        &visitor,
+                    )),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> serde::Deserializer<'de> for Cell {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.0)
+    }
+
+    fn deserialize_bool<V: serde::de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.0.trim() {
+            s if s.eq_ignore_ascii_case("true") => visitor.visit_bool(true),
+            s if s.eq_ignore_ascii_case("false") => visitor.visit_bool(false),
+            s => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(s),
+                &visitor,
+            )),
+        }
+    }
+
+    fn deserialize_option<V: serde::de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_enum<V: serde::de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        serde::de::IntoDeserializer::<serde_json::Error>::into_deserializer(self.0)
+            .deserialize_enum(name, variants, visitor)
+    }
+
+    parse_number! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    serde::forward_to_deserialize_any! {
+        i128 u128 char str string bytes byte_buf unit unit_struct newtype_struct seq
+        tuple tuple_struct map struct identifier ignored_any
+    }
 }
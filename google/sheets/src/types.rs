@@ -1108,8 +1108,12 @@ pub struct BasicFilter {
     /**
      * The default filter associated with a sheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub criteria: Option<FilterCriteria>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub criteria: std::collections::HashMap<String, FilterCriteria>,
     /**
      * The default filter associated with a sheet.
      */
@@ -5939,8 +5943,12 @@ pub struct FilterView {
     /**
      * A filter view.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub criteria: Option<FilterCriteria>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub criteria: std::collections::HashMap<String, FilterCriteria>,
     /**
      * A filter view.
      */
@@ -7743,8 +7751,12 @@ pub struct PivotTable {
     /**
      * A pivot table.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub criteria: Option<PivotFilterCriteria>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub criteria: std::collections::HashMap<String, PivotFilterCriteria>,
     /**
      * A pivot table.
      */
@@ -0,0 +1,398 @@
+//! Updates to a spreadsheet.
+//!
+//! `batchUpdate` takes a list of requests, each of which sets exactly one of
+//! the many fields of a `Request`. [`Updates`] builds them from what is being
+//! done rather than from the shape of the request, and hands out a [`Reply`]
+//! for the requests that answer with something, such as the properties of an
+//! added sheet.
+//!
+//! ```ignore
+//! let mut updates = Updates::new();
+//! let sheet = updates.add_sheet("Q1 Sales", Some(42));
+//! updates
+//!     .freeze(42, 1, 0)
+//!     .column_widths(42, 1, 6, 120)
+//!     .format(A1Range::sheet("").rows(1, 1).grid_range(42), &Format::new().bold(true));
+//!
+//! let replies = client.spreadsheets().apply(spreadsheet_id, &updates).await?.body;
+//! let properties = replies.get(&sheet);
+//! ```
+//!
+//! FROM: <https://developers.google.com/sheets/api/guides/batchupdate>
+use serde_json::{Map, Value, json};
+
+use crate::{
+    ClientResult, Response,
+    spreadsheets::Spreadsheets,
+    types::{
+        BatchUpdateSpreadsheetResponse, BooleanConditionType, Color, GridRange,
+        HorizontalAlignment, MergeType, NumberFormatType, ProtectedRange, Request, SheetProperties,
+        WrapStrategy,
+    },
+};
+
+/// A color from its red, green and blue components.
+pub fn rgb(red: u8, green: u8, blue: u8) -> Color {
+    Color {
+        alpha: 0.0,
+        red: red as f64 / 255.0,
+        green: green as f64 / 255.0,
+        blue: blue as f64 / 255.0,
+    }
+}
+
+/// The format of cells. Only what is set is changed, the rest of the format
+/// of the cells is left as is.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Format {
+    format: Map<String, Value>,
+    fields: Vec<&'static str>,
+}
+
+impl Format {
+    pub fn new() -> Self {
+        Format::default()
+    }
+
+    pub fn bold(self, bold: bool) -> Self {
+        self.set("textFormat.bold", json!(bold))
+    }
+
+    pub fn italic(self, italic: bool) -> Self {
+        self.set("textFormat.italic", json!(italic))
+    }
+
+    pub fn strikethrough(self, strikethrough: bool) -> Self {
+        self.set("textFormat.strikethrough", json!(strikethrough))
+    }
+
+    /// The size of the font in points.
+    pub fn font_size(self, points: u32) -> Self {
+        self.set("textFormat.fontSize", json!(points))
+    }
+
+    pub fn foreground(self, color: Color) -> Self {
+        self.set("textFormat.foregroundColor", json!(color))
+    }
+
+    pub fn background(self, color: Color) -> Self {
+        self.set("backgroundColor", json!(color))
+    }
+
+    pub fn horizontal_alignment(self, alignment: HorizontalAlignment) -> Self {
+        self.set("horizontalAlignment", json!(alignment))
+    }
+
+    pub fn wrap(self, strategy: WrapStrategy) -> Self {
+        self.set("wrapStrategy", json!(strategy))
+    }
+
+    /// How numbers are shown, `pattern` such as `#,##0.00` or `yyyy-mm-dd`,
+    /// or empty for the default of `kind` in the locale of the spreadsheet.
+    pub fn number_format(self, kind: NumberFormatType, pattern: &str) -> Self {
+        let mut format = json!({ "type": kind });
+        if !pattern.is_empty() {
+            format["pattern"] = json!(pattern);
+        }
+        self.set("numberFormat", format)
+    }
+
+    fn set(mut self, path: &'static str, value: Value) -> Self {
+        let mut parts = path.split('.').peekable();
+        let mut object = &mut self.format;
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                object.insert(part.to_string(), value);
+                break;
+            }
+            object = object
+                .entry(part)
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .unwrap();
+        }
+
+        if !self.fields.contains(&path) {
+            self.fields.push(path);
+        }
+        self
+    }
+
+    /// The mask of the fields that are set, relative to a `CellData`.
+    fn mask(&self) -> String {
+        self.fields
+            .iter()
+            .map(|f| format!("userEnteredFormat.{f}"))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// The reply to a request, read from the [`Replies`] once the updates are
+/// applied.
+pub struct Reply<T> {
+    index: usize,
+    extract: fn(crate::types::Response) -> Option<T>,
+}
+
+impl<T> Clone for Reply<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Reply<T> {}
+
+/// The replies to applied [`Updates`], in the order of the requests.
+#[derive(Debug, Clone)]
+pub struct Replies {
+    pub spreadsheet_id: String,
+    replies: Vec<crate::types::Response>,
+}
+
+impl Replies {
+    /// The reply to a request, `None` if Google did not answer it.
+    pub fn get<T>(&self, reply: &Reply<T>) -> Option<T> {
+        self.replies
+            .get(reply.index)
+            .cloned()
+            .and_then(reply.extract)
+    }
+}
+
+impl From<BatchUpdateSpreadsheetResponse> for Replies {
+    fn from(response: BatchUpdateSpreadsheetResponse) -> Self {
+        Replies {
+            spreadsheet_id: response.spreadsheet_id,
+            replies: response.replies,
+        }
+    }
+}
+
+/// A list of updates to apply to a spreadsheet at once, see the
+/// [module documentation](self).
+///
+/// Columns and rows are counted from 1 as in A1 notation, ranges of cells are
+/// `GridRange`s, see [`A1Range::grid_range`](crate::a1::A1Range::grid_range).
+#[derive(Debug, Clone, Default)]
+pub struct Updates {
+    requests: Vec<Value>,
+}
+
+impl Updates {
+    pub fn new() -> Self {
+        Updates::default()
+    }
+
+    /// The number of requests.
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Add any other request.
+    pub fn request(&mut self, request: &Request) -> ClientResult<&mut Self> {
+        self.requests.push(serde_json::to_value(request)?);
+        Ok(self)
+    }
+
+    /// Add a sheet. Giving it an ID lets the other updates refer to it,
+    /// otherwise Google picks one.
+    pub fn add_sheet(&mut self, title: &str, sheet_id: Option<i64>) -> Reply<SheetProperties> {
+        let mut properties = json!({ "title": title });
+        if let Some(sheet_id) = sheet_id {
+            properties["sheetId"] = json!(sheet_id);
+        }
+
+        self.push(json!({ "addSheet": { "properties": properties } }), |r| {
+            r.add_sheet?.properties
+        })
+    }
+
+    /// Keep the first `rows` and `columns` of a sheet in view when scrolling,
+    /// 0 to unfreeze them.
+    pub fn freeze(&mut self, sheet_id: i64, rows: u32, columns: u32) -> &mut Self {
+        self.requests.push(json!({
+            "updateSheetProperties": {
+                "properties": {
+                    "sheetId": sheet_id,
+                    "gridProperties": {
+                        "frozenRowCount": rows,
+                        "frozenColumnCount": columns,
+                    },
+                },
+                "fields": "gridProperties.frozenRowCount,gridProperties.frozenColumnCount",
+            }
+        }));
+        self
+    }
+
+    /// Set the width of the columns `first` through `last`.
+    pub fn column_widths(
+        &mut self,
+        sheet_id: i64,
+        first: u32,
+        last: u32,
+        pixels: u32,
+    ) -> &mut Self {
+        self.dimension_size(sheet_id, "COLUMNS", first, last, pixels)
+    }
+
+    /// Set the height of the rows `first` through `last`.
+    pub fn row_heights(&mut self, sheet_id: i64, first: u32, last: u32, pixels: u32) -> &mut Self {
+        self.dimension_size(sheet_id, "ROWS", first, last, pixels)
+    }
+
+    fn dimension_size(
+        &mut self,
+        sheet_id: i64,
+        dimension: &str,
+        first: u32,
+        last: u32,
+        pixels: u32,
+    ) -> &mut Self {
+        self.requests.push(json!({
+            "updateDimensionProperties": {
+                "range": {
+                    "sheetId": sheet_id,
+                    "dimension": dimension,
+                    "startIndex": first.saturating_sub(1),
+                    "endIndex": last,
+                },
+                "properties": { "pixelSize": pixels },
+                "fields": "pixelSize",
+            }
+        }));
+        self
+    }
+
+    /// Format every cell in a range.
+    pub fn format(&mut self, range: GridRange, format: &Format) -> &mut Self {
+        self.requests.push(json!({
+            "repeatCell": {
+                "range": range,
+                "cell": { "userEnteredFormat": format.format },
+                "fields": format.mask(),
+            }
+        }));
+        self
+    }
+
+    /// Format the cells of a range for which a condition holds, such as
+    /// `NumberGreater` with `["100"]` or `CustomFormula` with `["=$C2<0"]`.
+    /// Rules added later take precedence.
+    ///
+    /// Only bold, italic, strikethrough and colors can be set by a rule.
+    pub fn conditional_format(
+        &mut self,
+        range: GridRange,
+        condition: BooleanConditionType,
+        values: &[&str],
+        format: &Format,
+    ) -> &mut Self {
+        let values: Vec<Value> = values
+            .iter()
+            .map(|v| json!({ "userEnteredValue": v }))
+            .collect();
+
+        self.requests.push(json!({
+            "addConditionalFormatRule": {
+                "index": 0,
+                "rule": {
+                    "ranges": [range],
+                    "booleanRule": {
+                        "condition": { "type": condition, "values": values },
+                        "format": format.format,
+                    },
+                },
+            }
+        }));
+        self
+    }
+
+    /// Merge the cells of a range, all of them into one cell or each row or
+    /// column into its own.
+    pub fn merge(&mut self, range: GridRange, merge_type: MergeType) -> &mut Self {
+        self.requests.push(json!({
+            "mergeCells": { "range": range, "mergeType": merge_type }
+        }));
+        self
+    }
+
+    pub fn unmerge(&mut self, range: GridRange) -> &mut Self {
+        self.requests
+            .push(json!({ "unmergeCells": { "range": range } }));
+        self
+    }
+
+    /// Protect a range from being edited, or with `warning_only` only warn
+    /// before it is.
+    pub fn protect(
+        &mut self,
+        range: GridRange,
+        description: &str,
+        warning_only: bool,
+    ) -> Reply<ProtectedRange> {
+        self.push(
+            json!({
+                "addProtectedRange": {
+                    "protectedRange": {
+                        "range": range,
+                        "description": description,
+                        "warningOnly": warning_only,
+                    }
+                }
+            }),
+            |r| r.add_protected_range?.protected_range,
+        )
+    }
+
+    fn push<T>(
+        &mut self,
+        request: Value,
+        extract: fn(crate::types::Response) -> Option<T>,
+    ) -> Reply<T> {
+        self.requests.push(request);
+        Reply {
+            index: self.requests.len() - 1,
+            extract,
+        }
+    }
+}
+
+impl Spreadsheets {
+    /// Apply updates to a spreadsheet, all of them or, if any is not valid,
+    /// none.
+    pub async fn apply(
+        &self,
+        spreadsheet_id: &str,
+        updates: &Updates,
+    ) -> ClientResult<Response<Replies>> {
+        let url = self.client.url(
+            &format!(
+                "/v4/spreadsheets/{}:batchUpdate",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+            ),
+            None,
+        );
+        // The requests are sent as built, a `Request` would also send the
+        // defaults of the fields that were not set.
+        let resp: Response<BatchUpdateSpreadsheetResponse> = self
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(
+                        &json!({ "requests": updates.requests }),
+                    )?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await?;
+
+        Ok(Response::new(resp.status, resp.headers, resp.body.into()))
+    }
+}
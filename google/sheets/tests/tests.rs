@@ -1 +1,281 @@
+use serde::{Deserialize, Serialize};
+use wiremock::{
+    Mock, MockServer, Request, ResponseTemplate,
+    matchers::{method, path, query_param},
+};
 
+use sheets::{
+    Client,
+    a1::A1Range,
+    traits::SpreadsheetOps,
+    types::{BooleanConditionType, MergeType},
+    updates::{Format, Updates, rgb},
+};
+
+#[test]
+fn test_a1_range_parses_and_displays() {
+    for range in [
+        "'Q1 Sales'!A2:F",
+        "Sheet1!A2:F",
+        "'It''s'!A1",
+        "A:C",
+        "Sheet1!2:5",
+        "Sheet1",
+    ] {
+        assert_eq!(range.parse::<A1Range>().unwrap().to_string(), range);
+    }
+
+    let range: A1Range = "'It''s'!B3:AA".parse().unwrap();
+    assert_eq!(range.sheet, "It's");
+    assert_eq!((range.start_column, range.start_row), (Some(2), Some(3)));
+    assert_eq!((range.end_column, range.end_row), (Some(27), None));
+
+    assert_eq!(A1Range::sheet("A1").cell(1, 1).to_string(), "'A1'!A1");
+    assert_eq!(
+        A1Range::sheet("Sheet1")
+            .columns(1, 3)
+            .rows_from(2)
+            .to_string(),
+        "Sheet1!A2:C"
+    );
+    assert_eq!(
+        A1Range::sheet("Sheet1").rows_from(2).to_string(),
+        "Sheet1!A2:ZZZ"
+    );
+    assert_eq!(
+        A1Range::sheet("Sheet1").without_first_row().to_string(),
+        "Sheet1!A2:ZZZ"
+    );
+    assert_eq!(
+        "Sheet1!A2:ZZZ".parse::<A1Range>().unwrap().end_column,
+        Some(18278)
+    );
+    assert!("Sheet1!A0".parse::<A1Range>().is_err());
+    assert!("'Sheet1!A1".parse::<A1Range>().is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Order {
+    id: u32,
+    customer: String,
+    total: Option<f64>,
+}
+
+#[tokio::test]
+async fn test_rows_are_read_and_appended_by_header() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v4/spreadsheets/sheet/values/Sheet1%21A1%3AC"))
+        .and(query_param("valueRenderOption", "FORMATTED_VALUE"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "values": [
+                ["id", "customer", "total", ""],
+                ["1", "Ann", " 9.5"],
+                [],
+                ["2", "42", ""],
+            ],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v4/spreadsheets/sheet/values/Sheet1%21A1%3AC1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "values": [["customer", "id", "total"]],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v4/spreadsheets/sheet/values/Sheet1%21A1%3AC:append"))
+        .and(query_param("valueInputOption", "USER_ENTERED"))
+        .respond_with(|req: &Request| {
+            let body: serde_json::Value = req.body_json().unwrap();
+            assert_eq!(body["values"], serde_json::json!([["'Cy", "3", ""]]));
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "spreadsheetId": "sheet",
+            }))
+        })
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+    let range = A1Range::sheet("Sheet1").columns(1, 3).rows_from(1);
+
+    let orders: Vec<Order> = client
+        .spreadsheets()
+        .rows_get("sheet", &range)
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(
+        orders,
+        vec![
+            Order {
+                id: 1,
+                customer: "Ann".to_string(),
+                total: Some(9.5),
+            },
+            Order {
+                id: 2,
+                customer: "42".to_string(),
+                total: None,
+            },
+        ]
+    );
+
+    let appended = client
+        .spreadsheets()
+        .rows_append(
+            "sheet",
+            &range,
+            &[Order {
+                id: 3,
+                customer: "Cy".to_string(),
+                total: None,
+            }],
+        )
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(appended.spreadsheet_id, "sheet");
+}
+
+#[tokio::test]
+async fn test_rows_update_writes_a_header_and_quotes_strings() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v4/spreadsheets/sheet/values/%27Q1%2FQ2%27%21A1%3AC"))
+        .and(query_param("valueInputOption", "USER_ENTERED"))
+        .respond_with(|req: &Request| {
+            let body: serde_json::Value = req.body_json().unwrap();
+            assert_eq!(body["majorDimension"], "ROWS");
+            assert_eq!(
+                body["values"],
+                serde_json::json!([["'id", "'customer", "'total"], ["1", "'=SUM(A1)", "9.5"]])
+            );
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "spreadsheetId": "sheet",
+                "updatedRows": 2,
+            }))
+        })
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+    let range = A1Range::sheet("Q1/Q2").columns(1, 3).rows_from(1);
+
+    let updated = client
+        .spreadsheets()
+        .rows_update(
+            "sheet",
+            &range,
+            &[Order {
+                id: 1,
+                customer: "=SUM(A1)".to_string(),
+                total: Some(9.5),
+            }],
+        )
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(updated.updated_rows, 2);
+}
+
+#[tokio::test]
+async fn test_updates_send_only_what_is_set_and_parse_replies() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v4/spreadsheets/sheet:batchUpdate"))
+        .respond_with(|req: &Request| {
+            let body: serde_json::Value = req.body_json().unwrap();
+            let requests = body["requests"].as_array().unwrap();
+            assert_eq!(requests.len(), 7);
+            assert_eq!(
+                requests[2],
+                serde_json::json!({
+                    "updateDimensionProperties": {
+                        "range": { "sheetId": 42, "dimension": "COLUMNS", "startIndex": 0, "endIndex": 3 },
+                        "properties": { "pixelSize": 120 },
+                        "fields": "pixelSize",
+                    }
+                })
+            );
+            assert_eq!(
+                requests[3]["repeatCell"],
+                serde_json::json!({
+                    "range": { "sheetId": 42, "endRowIndex": 1 },
+                    "cell": { "userEnteredFormat": {
+                        "textFormat": { "bold": true },
+                        "backgroundColor": { "red": 1.0 },
+                    } },
+                    "fields": "userEnteredFormat.textFormat.bold,userEnteredFormat.backgroundColor",
+                })
+            );
+            assert_eq!(
+                requests[4]["addConditionalFormatRule"]["rule"]["booleanRule"]["condition"],
+                serde_json::json!({ "type": "NUMBER_LESS", "values": [{ "userEnteredValue": "0" }] })
+            );
+            assert_eq!(
+                requests[5],
+                serde_json::json!({ "mergeCells": {
+                    "range": { "sheetId": 42, "startRowIndex": 1, "endRowIndex": 2, "endColumnIndex": 2 },
+                    "mergeType": "MERGE_ALL",
+                } })
+            );
+
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "spreadsheetId": "sheet",
+                "replies": [
+                    { "addSheet": { "properties": { "sheetId": 42, "title": "Q1 Sales" } } },
+                    {}, {}, {}, {}, {},
+                    { "addProtectedRange": { "protectedRange": { "protectedRangeId": 7 } } },
+                ],
+            }))
+        })
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let mut updates = Updates::new();
+    let sheet = updates.add_sheet("Q1 Sales", Some(42));
+    let header = A1Range::default().rows(1, 1).grid_range(42);
+    updates
+        .freeze(42, 1, 0)
+        .column_widths(42, 1, 3, 120)
+        .format(
+            header.clone(),
+            &Format::new().bold(true).background(rgb(255, 0, 0)),
+        )
+        .conditional_format(
+            A1Range::default().columns(3, 3).grid_range(42),
+            BooleanConditionType::NumberLess,
+            &["0"],
+            &Format::new().foreground(rgb(255, 0, 0)),
+        )
+        .merge(
+            A1Range::default().columns(1, 2).rows(2, 2).grid_range(42),
+            MergeType::MergeAll,
+        );
+    let protected = updates.protect(header, "Header", true);
+    assert_eq!(updates.len(), 7);
+
+    let replies = client
+        .spreadsheets()
+        .apply("sheet", &updates)
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(replies.get(&sheet).unwrap().title, "Q1 Sales");
+    assert_eq!(replies.get(&protected).unwrap().protected_range_id, 7);
+}
@@ -0,0 +1,139 @@
+//! The data center of an account.
+//!
+//! Every account is served from one data center, such as `us21`, at
+//! `https://us21.api.mailchimp.com/3.0`. API keys end with theirs, as in
+//! `0123456789abcdef-us21`, so [`Client::new_with_api_key`] knows where to
+//! send requests from the key alone. An OAuth token does not say, the metadata
+//! of the token does: [`Client::get_access_token`] reads it once it has
+//! exchanged a code, and [`Client::set_data_center_from_metadata`] does for a
+//! token from elsewhere, or when that lookup failed.
+//!
+//! ```ignore
+//! let client = Client::new_with_api_key(api_key)?;
+//! let health = client.ping().get().await?;
+//!
+//! let mut client = Client::new(client_id, client_secret, redirect_uri, token, "");
+//! let metadata = client.set_data_center_from_metadata().await?;
+//! println!("{} is in {}", metadata.accountname, metadata.dc);
+//! ```
+//!
+//! FROM: <https://mailchimp.com/developer/marketing/docs/fundamentals/#api-structure>
+use std::env;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, ClientError, ClientResult};
+
+const METADATA_ENDPOINT: &str = "https://login.mailchimp.com/oauth2/metadata";
+
+/// The account an OAuth token is for.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct Metadata {
+    /// The data center of the account, such as `us21`.
+    #[serde(default)]
+    pub dc: String,
+    #[serde(default)]
+    pub role: String,
+    #[serde(default)]
+    pub accountname: String,
+    #[serde(default)]
+    pub user_id: i64,
+    #[serde(default)]
+    pub login_url: String,
+    /// Where the API of the account is, such as `https://us21.api.mailchimp.com`.
+    #[serde(default)]
+    pub api_endpoint: String,
+}
+
+/// The host of the API for the data center `api_key` ends with.
+pub fn host_for_api_key(api_key: &str) -> ClientResult<String> {
+    match api_key.trim().rsplit_once('-') {
+        Some((key, dc))
+            if !key.is_empty()
+                && !dc.is_empty()
+                && dc.chars().all(|c| c.is_ascii_alphanumeric()) =>
+        {
+            Ok(format!("https://{dc}.api.mailchimp.com/3.0"))
+        }
+        _ => Err(ClientError::InvalidApiKey),
+    }
+}
+
+impl Client {
+    /// Create a new Client struct that authenticates with an API key and sends
+    /// requests to the data center the key is for.
+    pub fn new_with_api_key<K>(api_key: K) -> ClientResult<Self>
+    where
+        K: ToString,
+    {
+        let api_key = api_key.to_string();
+        let mut client = Client::new("", "", "", "", "");
+        client.host = host_for_api_key(&api_key)?;
+        client.api_key = api_key;
+        Ok(client)
+    }
+
+    /// Create a new Client struct from an API key in the `MAILCHIMP_API_KEY`
+    /// environment variable.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the expected environment variable can not be found
+    pub fn new_with_api_key_from_env() -> ClientResult<Self> {
+        let api_key = env::var("MAILCHIMP_API_KEY").expect("must set MAILCHIMP_API_KEY");
+
+        Client::new_with_api_key(api_key)
+    }
+
+    /// Get the account the OAuth token of the client is for.
+    pub async fn get_metadata(&self) -> ClientResult<Metadata> {
+        let token = self.token.read().await.access_token.clone();
+        let resp = self
+            .client
+            .get(self.metadata_endpoint())
+            .header(reqwest::header::ACCEPT, "application/json")
+            .header(reqwest::header::AUTHORIZATION, format!("OAuth {token}"))
+            .send()
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let headers = resp.headers().clone();
+            return Err(ClientError::HttpError {
+                status,
+                headers,
+                error: resp.text().await?,
+            });
+        }
+
+        Ok(resp.json().await?)
+    }
+
+    /// Send requests to the data center of the account the OAuth token of the
+    /// client is for.
+    pub async fn set_data_center_from_metadata(&mut self) -> ClientResult<Metadata> {
+        let metadata = self.get_metadata().await?;
+        self.host = if metadata.api_endpoint.is_empty() {
+            format!("https://{}.api.mailchimp.com/3.0", metadata.dc)
+        } else {
+            format!("{}/3.0", metadata.api_endpoint.trim_end_matches('/'))
+        };
+        Ok(metadata)
+    }
+
+    /// Read the metadata of OAuth tokens from `metadata_endpoint` rather than
+    /// from Mailchimp, such as through a proxy. The host override of the API
+    /// does not apply to it.
+    pub fn with_metadata_endpoint<E>(&mut self, metadata_endpoint: E) -> &mut Self
+    where
+        E: ToString,
+    {
+        self.metadata_endpoint = Some(metadata_endpoint.to_string());
+        self
+    }
+
+    fn metadata_endpoint(&self) -> &str {
+        self.metadata_endpoint.as_deref().unwrap_or(METADATA_ENDPOINT)
+    }
+}
@@ -114,6 +114,7 @@ pub mod campaigns;
 pub mod connected_sites;
 pub mod conversations;
 pub mod customer_journeys;
+pub mod data_center;
 pub mod ecommerce;
 pub mod facebook_ads;
 pub mod file_manager;
@@ -165,6 +166,9 @@ pub enum ClientError {
     /// utf8 convertion error
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+    /// An API key does not end with the data center it is for
+    #[error("Invalid API key")]
+    InvalidApiKey,
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
@@ -246,6 +250,8 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
+    api_key: String,
+    metadata_endpoint: Option<String>,
 
     auto_refresh: bool,
     #[cfg(feature = "middleware")]
@@ -360,6 +366,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    api_key: String::new(),
+                    metadata_endpoint: None,
 
                     auto_refresh: false,
                     client,
@@ -582,13 +590,27 @@ impl Client {
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        // The token is good even when its metadata cannot be read, the data
+        // center can be looked up again with set_data_center_from_metadata.
+        if let Err(e) = self.set_data_center_from_metadata().await {
+            log::warn!("failed to look up the data center of the token: {e}");
+        }
+
         Ok(t)
     }
 
     async fn url_and_auth(&self, uri: &str) -> ClientResult<(reqwest::Url, Option<String>)> {
         let parsed_url = uri.parse::<reqwest::Url>()?;
 
-        let auth = format!("Bearer {}", self.token.read().await.access_token);
+        let auth = if self.api_key.is_empty() {
+            format!("Bearer {}", self.token.read().await.access_token)
+        } else {
+            let credentials = format!("anystring:{}", self.api_key);
+            format!(
+                "Basic {}",
+                base64::Engine::encode(&base64::engine::general_purpose::STANDARD, credentials)
+            )
+        };
         Ok((parsed_url, Some(auth)))
     }
 
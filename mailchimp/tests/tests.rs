@@ -1 +1,80 @@
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
 
+use mailchimp_api::{Client, ClientError, data_center::host_for_api_key};
+
+#[tokio::test]
+async fn test_api_keys_pick_their_data_center_and_use_basic_auth() {
+    assert_eq!(
+        host_for_api_key("0123456789abcdef-us21").unwrap(),
+        "https://us21.api.mailchimp.com/3.0"
+    );
+    assert!(matches!(
+        host_for_api_key("0123456789abcdef"),
+        Err(ClientError::InvalidApiKey)
+    ));
+    assert!(matches!(
+        Client::new_with_api_key("0123456789abcdef-"),
+        Err(ClientError::InvalidApiKey)
+    ));
+
+    let server = MockServer::start().await;
+
+    // base64 of `anystring:0123456789abcdef-us21`.
+    Mock::given(method("GET"))
+        .and(path("/ping"))
+        .and(header(
+            "Authorization",
+            "Basic YW55c3RyaW5nOjAxMjM0NTY3ODlhYmNkZWYtdXMyMQ==",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "health_status": "Everything's Chimpy!"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new_with_api_key("0123456789abcdef-us21").unwrap();
+    client.with_host_override(server.uri());
+
+    let health = client.ping().get().await.unwrap();
+    assert_eq!(health.body.health_status, "Everything's Chimpy!");
+}
+
+#[tokio::test]
+async fn test_oauth_tokens_pick_their_data_center_from_metadata() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/oauth2/metadata"))
+        .and(header("Authorization", "OAuth token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "dc": "us21",
+            "accountname": "Chimp",
+            "api_endpoint": format!("{}/", server.uri()),
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/3.0/ping"))
+        .and(header("Authorization", "Bearer token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "health_status": "Everything's Chimpy!"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("", "", "", "token", "");
+    client.with_metadata_endpoint(format!("{}/oauth2/metadata", server.uri()));
+    let metadata = client.set_data_center_from_metadata().await.unwrap();
+    assert_eq!(metadata.dc, "us21");
+    assert_eq!(metadata.accountname, "Chimp");
+
+    // Requests now go to the API endpoint of the metadata.
+    let health = client.ping().get().await.unwrap();
+    assert_eq!(health.body.health_status, "Everything's Chimpy!");
+}
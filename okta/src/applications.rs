@@ -82,6 +82,7 @@ impl Applications {
     pub async fn list_all(
         &self,
         q: &str,
+        limit: i64,
         filter: &str,
         expand: &str,
         include_non_deleted: bool,
@@ -99,6 +100,9 @@ impl Applications {
                 include_non_deleted.to_string(),
             ));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -114,6 +118,47 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/apps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates apps added to your organization with pagination. A subset of apps can be returned that match a supported filter expression or query.
+     */
+    pub fn list_stream(
+        &self,
+        q: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Application>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if include_non_deleted {
+            query_args.push((
+                "includeNonDeleted".to_string(),
+                include_non_deleted.to_string(),
+            ));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/apps?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Add Application.
      *
@@ -311,6 +356,28 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Certificate Signing Requests for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates Certificate Signing Requests for an application
+     */
+    pub fn list_csrs_fors_stream(
+        &self,
+        app_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Csr>> + Unpin + Send + use<> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Generate Certificate Signing Request for Application.
      *
@@ -499,6 +566,29 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Key Credentials for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates key credentials for an application
+     */
+    pub fn list_keys_stream(
+        &self,
+        app_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/credentials/keys",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/apps/{appId}/credentials/keys/generate` endpoint.
      *
@@ -688,6 +778,36 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
+     *
+     * As opposed to `list_all_scope_consent_grants`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists all scope consent grants for the application
+     */
+    pub fn list_scope_consent_grants_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>>
+    + Unpin
+    + Send
+    + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/grants?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/apps/{appId}/grants` endpoint.
      *
@@ -861,12 +981,16 @@ impl Applications {
         &self,
         app_id: &str,
         q: &str,
+        limit: i64,
         expand: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::ApplicationGroupAssignment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -889,6 +1013,46 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Groups Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/groups` endpoint.
+     *
+     * As opposed to `list_all_group_assignments`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates group assignments for an application.
+     */
+    pub fn list_group_assignments_stream(
+        &self,
+        app_id: &str,
+        q: &str,
+        limit: i64,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplicationGroupAssignment>>
+    + Unpin
+    + Send
+    + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/groups?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Get Assigned Group for Application.
      *
@@ -1120,11 +1284,15 @@ impl Applications {
         &self,
         app_id: &str,
         expand: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Token>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -1144,6 +1312,38 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_tokens_fors`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists all tokens for the application
+     */
+    pub fn list_o_auth_2_tokens_fors_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Token>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/tokens?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/apps/{appId}/tokens` endpoint.
      *
@@ -1327,6 +1527,7 @@ impl Applications {
         app_id: &str,
         q: &str,
         query_scope: &str,
+        limit: i64,
         filter: &str,
         expand: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::AppUser>>> {
@@ -1337,6 +1538,9 @@ impl Applications {
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -1362,6 +1566,52 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Users Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates all assigned [application users](#application-user-model) for an application.
+     */
+    pub fn list_users_stream(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AppUser>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !query_scope.is_empty() {
+            query_args.push(("query_scope".to_string(), query_scope.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/users?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Assign User to Application for SSO & Provisioning.
      *
@@ -0,0 +1,491 @@
+//! Authenticate as an OAuth 2.0 service app instead of with an `SSWS` API token.
+//!
+//! The service app signs a `private_key_jwt` client assertion and exchanges it
+//! for an access token with the `client_credentials` grant. Access tokens are
+//! cached per set of scopes, and are bound to a key with DPoP proofs when the
+//! app requires it.
+//!
+//! FROM: <https://developer.okta.com/docs/guides/implement-oauth-for-okta-serviceapp/main/>
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use jsonwebtoken as jwt;
+use p256::ecdsa::{Signature, SigningKey, signature::Signer};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{Client, ClientError, ClientResult};
+
+const TOKEN_PATH: &str = "/oauth2/v1/token";
+const CLIENT_ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+const DPOP_NONCE: &str = "dpop-nonce";
+
+// Okta rejects client assertions that expire more than an hour after they
+// were issued, they are only used once so keep them short lived.
+const CLIENT_ASSERTION_LIFE: Duration = Duration::from_secs(60 * 5);
+// Refresh a minute before the token actually expires to avoid problems with
+// clock drift.
+const ACCESS_TOKEN_REFRESH_BUFFER: Duration = Duration::from_secs(60);
+
+/// The private key of a service app, used to sign client assertions.
+#[derive(Clone)]
+pub struct PrivateKey {
+    key: jwt::EncodingKey,
+    algorithm: jwt::Algorithm,
+    kid: Option<String>,
+}
+
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrivateKey")
+            .field("algorithm", &self.algorithm)
+            .field("kid", &self.kid)
+            .field("key", &"***")
+            .finish()
+    }
+}
+
+impl PrivateKey {
+    /// Load an RSA (`RS256`) or P-256 (`ES256`) private key from PEM.
+    pub fn from_pem(pem: &[u8]) -> ClientResult<Self> {
+        let (key, algorithm) = match jwt::EncodingKey::from_rsa_pem(pem) {
+            Ok(key) => (key, jwt::Algorithm::RS256),
+            Err(_) => (jwt::EncodingKey::from_ec_pem(pem)?, jwt::Algorithm::ES256),
+        };
+
+        Ok(PrivateKey {
+            key,
+            algorithm,
+            kid: None,
+        })
+    }
+
+    /// Load an RSA or P-256 private key from a JWK, as generated by the Okta
+    /// Admin Console. The `kid` of the JWK is sent along with the assertions.
+    pub fn from_jwk(jwk: &str) -> ClientResult<Self> {
+        let jwk: PrivateJwk = serde_json::from_str(jwk)?;
+        let field = |name: &str, value: &Option<String>| -> ClientResult<Vec<u8>> {
+            let value = value
+                .as_deref()
+                .ok_or_else(|| ClientError::InvalidPrivateKey(format!("missing `{name}`")))?;
+            URL_SAFE_NO_PAD
+                .decode(value)
+                .map_err(|e| ClientError::InvalidPrivateKey(format!("`{name}`: {e}")))
+        };
+
+        let (key, algorithm) = match jwk.kty.as_str() {
+            "RSA" => {
+                let mut der = der_integer(&[0]);
+                for (name, value) in [
+                    ("n", &jwk.n),
+                    ("e", &jwk.e),
+                    ("d", &jwk.d),
+                    ("p", &jwk.p),
+                    ("q", &jwk.q),
+                    ("dp", &jwk.dp),
+                    ("dq", &jwk.dq),
+                    ("qi", &jwk.qi),
+                ] {
+                    der.extend(der_integer(&field(name, value)?));
+                }
+                (
+                    jwt::EncodingKey::from_rsa_der(&der_tlv(0x30, &der)),
+                    jwt::Algorithm::RS256,
+                )
+            }
+            "EC" if jwk.crv.as_deref() == Some("P-256") => {
+                use p256::pkcs8::EncodePrivateKey;
+
+                let der = p256::SecretKey::from_slice(&field("d", &jwk.d)?)
+                    .map_err(|e| ClientError::InvalidPrivateKey(e.to_string()))?
+                    .to_pkcs8_der()
+                    .map_err(|e| ClientError::InvalidPrivateKey(e.to_string()))?;
+                (
+                    jwt::EncodingKey::from_ec_der(der.as_bytes()),
+                    jwt::Algorithm::ES256,
+                )
+            }
+            kty => {
+                return Err(ClientError::InvalidPrivateKey(format!(
+                    "unsupported key type `{kty}`"
+                )));
+            }
+        };
+
+        Ok(PrivateKey {
+            key,
+            algorithm,
+            kid: jwk.kid,
+        })
+    }
+
+    /// Set the `kid` sent along with the assertions, this is required when the
+    /// service app has more than one public key registered.
+    pub fn with_key_id<S: ToString>(mut self, kid: S) -> Self {
+        self.kid = Some(kid.to_string());
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct PrivateJwk {
+    kty: String,
+    #[serde(default)]
+    kid: Option<String>,
+    #[serde(default)]
+    crv: Option<String>,
+    #[serde(default)]
+    n: Option<String>,
+    #[serde(default)]
+    e: Option<String>,
+    #[serde(default)]
+    d: Option<String>,
+    #[serde(default)]
+    p: Option<String>,
+    #[serde(default)]
+    q: Option<String>,
+    #[serde(default)]
+    dp: Option<String>,
+    #[serde(default)]
+    dq: Option<String>,
+    #[serde(default)]
+    qi: Option<String>,
+}
+
+/// An OAuth 2.0 service app.
+///
+/// Clones share the same token cache, so a client can be cloned with different
+/// scopes without fetching tokens it already has.
+#[derive(Clone)]
+pub struct ServiceApp {
+    client_id: String,
+    key: PrivateKey,
+    scopes: Vec<String>,
+    dpop: Option<Arc<DpopKey>>,
+    tokens: Arc<tokio::sync::Mutex<HashMap<String, AccessToken>>>,
+}
+
+impl fmt::Debug for ServiceApp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServiceApp")
+            .field("client_id", &self.client_id)
+            .field("key", &self.key)
+            .field("scopes", &self.scopes)
+            .field("dpop", &self.dpop.is_some())
+            .finish()
+    }
+}
+
+impl ServiceApp {
+    /// Create a new service app from its client id and private key.
+    pub fn new<C: ToString>(client_id: C, key: PrivateKey) -> Self {
+        ServiceApp {
+            client_id: client_id.to_string(),
+            key,
+            scopes: Default::default(),
+            dpop: None,
+            tokens: Default::default(),
+        }
+    }
+
+    /// Set the scopes to request, e.g. `okta.users.read`.
+    pub fn with_scopes<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        self.scopes = scopes.into_iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Bind access tokens to a freshly generated key with DPoP proofs.
+    ///
+    /// This is required when "Require Demonstrating Proof of Possession (DPoP)
+    /// header in token requests" is enabled for the service app.
+    pub fn with_dpop(mut self) -> Self {
+        self.dpop = Some(Arc::new(DpopKey::generate()));
+        self.tokens = Default::default();
+        self
+    }
+
+    /// Get the value of the `Authorization` header, fetching a new access
+    /// token if there is no cached one for these scopes.
+    pub(crate) async fn authorization(&self, client: &Client) -> ClientResult<String> {
+        let mut scopes = self.scopes.clone();
+        scopes.sort();
+        let scope = scopes.join(" ");
+
+        let mut tokens = self.tokens.lock().await;
+        if let Some(token) = tokens.get(&scope) {
+            if token.expires_at > SystemTime::now() + ACCESS_TOKEN_REFRESH_BUFFER {
+                return Ok(token.authorization());
+            }
+        }
+
+        let token = self.fetch_token(client, &scope).await?;
+        let authorization = token.authorization();
+        tokens.insert(scope, token);
+
+        Ok(authorization)
+    }
+
+    async fn fetch_token(&self, client: &Client, scope: &str) -> ClientResult<AccessToken> {
+        let url = client.url(TOKEN_PATH, None);
+
+        // With DPoP, Okta rejects the first request without a nonce and returns
+        // the nonce to use, so allow for one retry.
+        let mut retried = false;
+        loop {
+            let form = [
+                ("grant_type", "client_credentials"),
+                ("scope", scope),
+                ("client_assertion_type", CLIENT_ASSERTION_TYPE),
+                ("client_assertion", &self.client_assertion(&url)?),
+            ];
+            let mut req = client
+                .client
+                .request(http::Method::POST, &url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .header(
+                    reqwest::header::CONTENT_TYPE,
+                    reqwest::header::HeaderValue::from_static("application/x-www-form-urlencoded"),
+                )
+                .body(serde_urlencoded::to_string(form).unwrap());
+            if let Some(dpop) = &self.dpop {
+                req = req.header("DPoP", dpop.proof("POST", &url, None)?);
+            }
+
+            let response = req.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let response_body = response.bytes().await?;
+
+            let nonce = headers.get(DPOP_NONCE).and_then(|n| n.to_str().ok());
+            if let (Some(dpop), Some(nonce)) = (&self.dpop, nonce) {
+                *dpop.nonce.lock().unwrap() = Some(nonce.to_string());
+            }
+
+            if status.is_success() {
+                let token: TokenResponse = serde_json::from_slice(&response_body)?;
+                return Ok(AccessToken {
+                    token_type: token.token_type,
+                    access_token: token.access_token,
+                    expires_at: SystemTime::now() + Duration::from_secs(token.expires_in),
+                });
+            }
+
+            if self.dpop.is_some() && nonce.is_some() && !retried {
+                retried = true;
+                continue;
+            }
+
+            return Err(ClientError::HttpError {
+                status,
+                headers,
+                error: String::from_utf8_lossy(&response_body).into(),
+            });
+        }
+    }
+
+    fn client_assertion(&self, audience: &str) -> ClientResult<String> {
+        let iat = unix_now();
+        let claims = ClientAssertion {
+            iss: &self.client_id,
+            sub: &self.client_id,
+            aud: audience,
+            iat,
+            exp: iat + CLIENT_ASSERTION_LIFE.as_secs(),
+            jti: uuid::Uuid::new_v4().to_string(),
+        };
+
+        let mut header = jwt::Header::new(self.key.algorithm);
+        header.kid = self.key.kid.clone();
+        Ok(jwt::encode(&header, &claims, &self.key.key)?)
+    }
+}
+
+#[derive(Serialize)]
+struct ClientAssertion<'a> {
+    iss: &'a str,
+    sub: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+    jti: String,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    token_type: String,
+    access_token: String,
+    expires_in: u64,
+}
+
+#[derive(Clone)]
+struct AccessToken {
+    token_type: String,
+    access_token: String,
+    expires_at: SystemTime,
+}
+
+impl AccessToken {
+    fn authorization(&self) -> String {
+        format!("{} {}", self.token_type, self.access_token)
+    }
+}
+
+/// The key DPoP proofs are signed with.
+///
+/// FROM: <https://developer.okta.com/docs/guides/dpop/nonoktaresourceserver/main/>
+struct DpopKey {
+    key: SigningKey,
+    jwk: serde_json::Value,
+    nonce: Mutex<Option<String>>,
+}
+
+impl DpopKey {
+    fn generate() -> Self {
+        let key = SigningKey::random(&mut rand::rngs::OsRng);
+        let point = key.verifying_key().to_encoded_point(false);
+        let jwk = serde_json::json!({
+            "kty": "EC",
+            "crv": "P-256",
+            "x": URL_SAFE_NO_PAD.encode(point.x().unwrap()),
+            "y": URL_SAFE_NO_PAD.encode(point.y().unwrap()),
+        });
+
+        DpopKey {
+            key,
+            jwk,
+            nonce: Mutex::new(None),
+        }
+    }
+
+    // Token requests carry the nonce Okta handed out, requests to the API
+    // carry a hash of the access token instead.
+    fn proof(&self, method: &str, url: &str, access_token: Option<&str>) -> ClientResult<String> {
+        let mut htu = url.parse::<reqwest::Url>()?;
+        htu.set_query(None);
+        htu.set_fragment(None);
+
+        let header = serde_json::json!({
+            "typ": "dpop+jwt",
+            "alg": "ES256",
+            "jwk": self.jwk,
+        });
+        let mut claims = serde_json::json!({
+            "htm": method,
+            "htu": htu.as_str(),
+            "iat": unix_now(),
+            "jti": uuid::Uuid::new_v4().to_string(),
+        });
+        match access_token {
+            Some(token) => {
+                claims["ath"] = URL_SAFE_NO_PAD.encode(Sha256::digest(token)).into();
+            }
+            None => {
+                if let Some(nonce) = self.nonce.lock().unwrap().as_deref() {
+                    claims["nonce"] = nonce.into();
+                }
+            }
+        }
+
+        let message = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?),
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&claims)?)
+        );
+        let signature: Signature = self.key.sign(message.as_bytes());
+        Ok(format!(
+            "{message}.{}",
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        ))
+    }
+}
+
+impl Client {
+    /// Create a new Client struct that authenticates as an OAuth 2.0 service app.
+    ///
+    /// Use `with_host_override` to point it at your Okta org, the token
+    /// endpoint is on the same host.
+    pub fn new_with_service_app(service_app: ServiceApp) -> Self {
+        let mut client = Client::new("");
+        client.service_app = Some(service_app);
+        client
+    }
+
+    /// Get a copy of this client that requests access tokens with different
+    /// scopes. The token cache is shared with the original client.
+    ///
+    /// This has no effect on clients using an `SSWS` API token.
+    pub fn with_scopes<I, S>(&self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        let mut client = self.clone();
+        client.service_app = client.service_app.map(|s| s.with_scopes(scopes));
+        client
+    }
+
+    /// Get the `DPoP` header for a request, if the access token is DPoP bound.
+    pub(crate) fn dpop_proof(
+        &self,
+        method: &reqwest::Method,
+        url: &reqwest::Url,
+        auth: Option<&str>,
+    ) -> ClientResult<Option<String>> {
+        let dpop = match self.service_app.as_ref().and_then(|s| s.dpop.as_ref()) {
+            Some(dpop) => dpop,
+            None => return Ok(None),
+        };
+        match auth.and_then(|a| a.strip_prefix("DPoP ")) {
+            Some(token) => Ok(Some(dpop.proof(
+                method.as_str(),
+                url.as_str(),
+                Some(token),
+            )?)),
+            None => Ok(None),
+        }
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn der_tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    let len = content.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes = len.to_be_bytes();
+        let bytes = &bytes[bytes.iter().position(|b| *b != 0).unwrap()..];
+        out.push(0x80 | bytes.len() as u8);
+        out.extend_from_slice(bytes);
+    }
+    out.extend_from_slice(content);
+    out
+}
+
+// A positive DER INTEGER from big-endian bytes.
+fn der_integer(bytes: &[u8]) -> Vec<u8> {
+    let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    let mut content = bytes[start..].to_vec();
+    if content.first().is_none_or(|b| *b & 0x80 != 0) {
+        content.insert(0, 0);
+    }
+    der_tlv(0x02, &content)
+}
@@ -62,8 +62,12 @@ impl AuthorizationServers {
     pub async fn list_all(
         &self,
         q: &str,
+        limit: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServer>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !limit.is_empty() {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -81,6 +85,34 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_stream(
+        &self,
+        q: &str,
+        limit: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServer>>
+    + Unpin
+    + Send
+    + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !limit.is_empty() {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/authorizationServers?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers` endpoint.
      *
@@ -247,6 +279,27 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_claims`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_o_auth_2_claims_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Claim>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/claims",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
      *
@@ -436,6 +489,27 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_clients_fors`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_o_auth_2_clients_fors_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Client>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/clients",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
      *
@@ -499,11 +573,15 @@ impl AuthorizationServers {
         auth_server_id: &str,
         client_id: &str,
         expand: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -524,6 +602,40 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_refresh_tokens_for_and_clients_stream(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2RefreshToken>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens?{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
      *
@@ -695,6 +807,27 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_keys_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/credentials/keys",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/credentials/lifecycle/keyRotate` endpoint.
      *
@@ -838,6 +971,29 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
+     *
+     * As opposed to `list_all_policies`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_policies_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServerPolicy>>
+    + Unpin
+    + Send
+    + use<> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/policies",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
      *
@@ -1098,6 +1254,31 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates all policy rules for the specified Custom Authorization Server and Policy.
+     */
+    pub fn list_policy_rules_stream(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServerPolicyRule>>
+    + Unpin
+    + Send
+    + use<> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/policies/{}/rules",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
      *
@@ -1379,6 +1560,7 @@ impl AuthorizationServers {
         q: &str,
         filter: &str,
         cursor: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Scope>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
@@ -1387,6 +1569,9 @@ impl AuthorizationServers {
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -1409,6 +1594,46 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_scopes`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_o_auth_2_scopes_stream(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Scope>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !cursor.is_empty() {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/scopes?{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
      *
@@ -47,6 +47,20 @@ impl EventHooks {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/eventHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::EventHook>> + Unpin + Send + use<>
+    {
+        let url = self.client.url("/api/v1/eventHooks", None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/eventHooks` endpoint.
      *
@@ -47,6 +47,20 @@ impl Features {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/features` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + Unpin + Send + use<>
+    {
+        let url = self.client.url("/api/v1/features", None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/features/{featureId}` endpoint.
      *
@@ -135,6 +149,27 @@ impl Features {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependencies` endpoint.
+     *
+     * As opposed to `list_all_dependencies`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_dependencies_stream(
+        &self,
+        feature_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/features/{}/dependencies",
+                crate::progenitor_support::encode_path(feature_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
      *
@@ -193,6 +228,27 @@ impl Features {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
+     *
+     * As opposed to `list_all_dependents`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_dependents_stream(
+        &self,
+        feature_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/features/{}/dependents",
+                crate::progenitor_support::encode_path(feature_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/features/{featureId}/{lifecycle}` endpoint.
      *
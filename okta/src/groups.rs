@@ -75,12 +75,16 @@ impl Groups {
         &self,
         q: &str,
         search: &str,
+        limit: i64,
         expand: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -99,6 +103,39 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates groups in your organization with pagination. A subset of groups can be returned that match a supported filter expression or query.
+     */
+    pub fn list_stream(
+        &self,
+        q: &str,
+        search: &str,
+        limit: i64,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + Send + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/groups?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Add Group.
      *
@@ -180,6 +217,7 @@ impl Groups {
      */
     pub async fn list_all_rules(
         &self,
+        limit: i64,
         search: &str,
         expand: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::GroupRule>>> {
@@ -187,6 +225,9 @@ impl Groups {
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !search.is_empty() {
             query_args.push(("search".to_string(), search.to_string()));
         }
@@ -204,6 +245,38 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Group Rules.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/rules` endpoint.
+     *
+     * As opposed to `list_all_rules`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists all group rules for your organization.
+     */
+    pub fn list_rules_stream(
+        &self,
+        limit: i64,
+        search: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GroupRule>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/groups/rules?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Create Group Rule.
      *
@@ -543,11 +616,18 @@ impl Groups {
     pub async fn list_all_assigned_applications_fors(
         &self,
         group_id: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::Application>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/api/v1/groups/{}/apps",
+                "/api/v1/groups/{}/apps?{}",
                 crate::progenitor_support::encode_path(group_id),
+                query_
             ),
             None,
         );
@@ -561,6 +641,36 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Assigned Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/apps` endpoint.
+     *
+     * As opposed to `list_all_assigned_applications_fors`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates all applications that are assigned to a group.
+     */
+    pub fn list_assigned_applications_fors_stream(
+        &self,
+        group_id: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Application>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/apps?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
      *
@@ -634,6 +744,33 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_assigned_roles_stream(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Role>> + Unpin + Send + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/groups/{groupId}/roles` endpoint.
      *
@@ -799,12 +936,19 @@ impl Groups {
         &self,
         group_id: &str,
         role_id: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/api/v1/groups/{}/roles/{}/targets/catalog/apps",
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps?{}",
                 crate::progenitor_support::encode_path(group_id),
                 crate::progenitor_support::encode_path(role_id),
+                query_
             ),
             None,
         );
@@ -818,6 +962,36 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists all App targets for an `APP_ADMIN` Role assigned to a Group. This methods return list may include full Applications or Instances. The response for an instance will have an `ID` value, while Application will not have an ID.
+     */
+    pub fn list_application_targets_for_administrator_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CatalogApplication>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps?{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}` endpoint.
      *
@@ -1029,12 +1203,19 @@ impl Groups {
         &self,
         group_id: &str,
         role_id: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/api/v1/groups/{}/roles/{}/targets/groups",
+                "/api/v1/groups/{}/roles/{}/targets/groups?{}",
                 crate::progenitor_support::encode_path(group_id),
                 crate::progenitor_support::encode_path(role_id),
+                query_
             ),
             None,
         );
@@ -1048,6 +1229,35 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_all_targets_for_roles`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_targets_for_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + Send + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles/{}/targets/groups?{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}` endpoint.
      *
@@ -1177,11 +1387,18 @@ impl Groups {
     pub async fn list_all_users(
         &self,
         group_id: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/api/v1/groups/{}/users",
+                "/api/v1/groups/{}/users?{}",
                 crate::progenitor_support::encode_path(group_id),
+                query_
             ),
             None,
         );
@@ -1195,6 +1412,35 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Group Members.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates all users that are a member of a group.
+     */
+    pub fn list_users_stream(
+        &self,
+        group_id: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + Unpin + Send + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/users?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Add User to Group.
      *
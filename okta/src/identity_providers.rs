@@ -69,9 +69,13 @@ impl IdentityProviders {
     pub async fn list_all(
         &self,
         q: &str,
+        limit: i64,
         type_: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::IdentityProvider>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -90,6 +94,36 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Identity Providers.
+     *
+     * This function performs a `GET` to the `/api/v1/idps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates IdPs in your organization with pagination. A subset of IdPs can be returned that match a supported filter expression or query.
+     */
+    pub fn list_stream(
+        &self,
+        q: &str,
+        limit: i64,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProvider>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/idps?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Add Identity Provider.
      *
@@ -161,8 +195,16 @@ impl IdentityProviders {
      */
     pub async fn list_all_keys(
         &self,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
-        let url = self.client.url("/api/v1/idps/credentials/keys", None);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/idps/credentials/keys?{}", query_), None);
         self.client
             .get_all_pages(
                 &url,
@@ -173,6 +215,30 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Keys.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates IdP key credentials.
+     */
+    pub fn list_keys_stream(
+        &self,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/idps/credentials/keys?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Add X.509 Certificate Public Key.
      *
@@ -412,6 +478,28 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Certificate Signing Requests for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates Certificate Signing Requests for an IdP
+     */
+    pub fn list_csrs_fors_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Csr>> + Unpin + Send + use<> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Generate Certificate Signing Request for IdP.
      *
@@ -606,6 +694,29 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Signing Key Credentials for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_signing_keys`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates signing key credentials for an IdP
+     */
+    pub fn list_signing_keys_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/credentials/keys",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Generate New IdP Signing Key Credential.
      *
@@ -850,6 +961,31 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * Find Users.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users` endpoint.
+     *
+     * As opposed to `list_all_application_users`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Find all the users linked to an identity provider
+     */
+    pub fn list_application_users_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProviderApplicationUser>>
+    + Unpin
+    + Send
+    + use<> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/users",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}` endpoint.
      *
@@ -1021,4 +1157,29 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * Social Authentication Token Operation.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}/credentials/tokens` endpoint.
+     *
+     * As opposed to `list_all_social_auth_tokens`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Fetches the tokens minted by the Social Authentication Provider when the user authenticates with Okta via Social Auth.
+     */
+    pub fn list_social_auth_tokens_stream(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SocialAuthToken>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/users/{}/credentials/tokens",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
 }
@@ -71,6 +71,28 @@ impl InlineHooks {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/inlineHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_stream(
+        &self,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InlineHook>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/inlineHooks?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/inlineHooks` endpoint.
      *
@@ -191,7 +213,7 @@ impl InlineHooks {
     pub async fn execute(
         &self,
         inline_hook_id: &str,
-        body: &crate::types::Links,
+        body: &crate::types::ResponseLinks,
     ) -> ClientResult<crate::Response<crate::types::InlineHookResponse>> {
         let url = self.client.url(
             &format!(
@@ -74,6 +74,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod applications;
+pub mod auth;
 pub mod authorization_servers;
 pub mod domains;
 pub mod event_hooks;
@@ -82,6 +83,7 @@ pub mod groups;
 pub mod identity_providers;
 pub mod inline_hooks;
 pub mod linked_objects;
+pub mod log_stream;
 pub mod logs;
 pub mod network_zones;
 pub mod policies;
@@ -127,6 +129,13 @@ pub enum ClientError {
     /// utf8 convertion error
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+
+    /// JWT errors from auth.rs
+    #[error(transparent)]
+    JsonWebTokenError(#[from] jsonwebtoken::errors::Error),
+    /// The private key of a service app could not be used
+    #[error("Invalid private key: {0}")]
+    InvalidPrivateKey(String),
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
@@ -197,6 +206,7 @@ pub struct Client {
     host: String,
     host_override: Option<String>,
     token: String,
+    service_app: Option<crate::auth::ServiceApp>,
 
     #[cfg(feature = "middleware")]
     client: reqwest_middleware::ClientWithMiddleware,
@@ -242,6 +252,7 @@ impl Client {
                     host,
                     host_override: None,
                     token: token.to_string(),
+                    service_app: None,
 
                     client,
                 }
@@ -295,7 +306,10 @@ impl Client {
 
     async fn url_and_auth(&self, uri: &str) -> ClientResult<(reqwest::Url, Option<String>)> {
         let parsed_url = uri.parse::<reqwest::Url>()?;
-        let auth = format!("SSWS {}", self.token);
+        let auth = match &self.service_app {
+            Some(service_app) => service_app.authorization(self).await?,
+            None => format!("SSWS {}", self.token),
+        };
         Ok((parsed_url, Some(auth)))
     }
 
@@ -306,6 +320,7 @@ impl Client {
         message: Message,
     ) -> ClientResult<reqwest::Response> {
         let (url, auth) = self.url_and_auth(uri).await?;
+        let dpop = self.dpop_proof(&method, &url, auth.as_deref())?;
         let instance = <&Client>::clone(&self);
         let mut req = instance.client.request(method.clone(), url);
         // Set the default headers.
@@ -329,6 +344,9 @@ impl Client {
         if let Some(auth_str) = auth {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
+        if let Some(proof) = dpop {
+            req = req.header("DPoP", proof);
+        }
         if let Some(body) = message.body {
             req = req.body(body);
         }
@@ -687,25 +705,28 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        let mut global_items = Vec::new();
-        let (new_link, mut response) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !response.body.is_empty() {
-            global_items.append(&mut response.body);
-            // We need to get the next link.
-            if let Some(url) = &link {
-                let url = reqwest::Url::parse(&url.0)?;
-                let (new_link, new_response) = self.get_pages_url(&url).await?;
-                link = new_link;
-                response = new_response;
+        let (
+            mut link,
+            crate::Response {
+                mut status,
+                mut headers,
+                body: mut global_items,
+            },
+        ) = self.get_pages(uri).await?;
+        // Follow the `rel="next"` links until there are no more pages.
+        while let Some(url) = link {
+            let url = reqwest::Url::parse(&url.0)?;
+            let (new_link, mut response) = self.get_pages_url(&url).await?;
+            if response.body.is_empty() {
+                break;
             }
+            global_items.append(&mut response.body);
+            link = new_link.filter(|l| l.0 != url.as_str());
+            status = response.status;
+            headers = response.headers;
         }
 
-        Ok(Response::new(
-            response.status,
-            response.headers,
-            global_items,
-        ))
+        Ok(Response::new(status, headers, global_items))
     }
 
     #[allow(dead_code)]
@@ -732,6 +753,41 @@ impl Client {
             .await
     }
 
+    /// Stream the items of paginated results, fetching the next page only once the current one has been consumed.
+    #[allow(dead_code)]
+    fn get_pages_stream<D>(
+        &self,
+        uri: &str,
+    ) -> impl futures::Stream<Item = ClientResult<D>> + Unpin + Send + use<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        use futures::TryStreamExt;
+
+        let client = self.clone();
+        Box::pin(
+            futures::stream::try_unfold(Some(uri.to_string()), move |next| {
+                let client = client.clone();
+                async move {
+                    let url = match next {
+                        Some(url) => url,
+                        None => return ClientResult::Ok(None),
+                    };
+                    let (link, response) = client.get_pages::<D>(&url).await?;
+                    if response.body.is_empty() {
+                        return Ok(None);
+                    }
+                    let next = link.map(|l| l.0).filter(|l| *l != url);
+                    ClientResult::Ok(Some((
+                        futures::stream::iter(response.body.into_iter().map(Ok)),
+                        next,
+                    )))
+                }
+            })
+            .try_flatten(),
+        )
+    }
+
     #[allow(dead_code)]
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
     where
@@ -55,6 +55,22 @@ impl LinkedObjects {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
+     *
+     * As opposed to `list_all_definitions`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_definitions_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LinkedObject>> + Unpin + Send + use<>
+    {
+        let url = self
+            .client
+            .url("/api/v1/meta/schemas/user/linkedObjects", None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
      *
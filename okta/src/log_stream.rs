@@ -0,0 +1,252 @@
+//! Continuous ingestion of the Okta System Log.
+//!
+//! Okta keeps returning a `rel="next"` link for polling requests (a `since`
+//! bound with an ascending sort order), so a tail never reaches the "end" of
+//! the log, it simply gets empty pages until new events are published.
+//!
+//! FROM: <https://developer.okta.com/docs/reference/api/system-log/#polling-requests>
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, ClientError, ClientResult};
+
+const X_RATE_LIMIT_REMAINING: &str = "x-rate-limit-remaining";
+const X_RATE_LIMIT_RESET: &str = "x-rate-limit-reset";
+
+/// The position of a [`LogStream`] in the System Log.
+///
+/// Persist this after every page to be able to resume a tail where it left off.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct LogCheckpoint {
+    /// The last `rel="next"` link returned by Okta, this carries the `after` cursor.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub next_url: String,
+    /// The `uuid` of the last event that was returned.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub uuid: String,
+    /// The `published` time of the last event that was returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published: Option<DateTime<Utc>>,
+}
+
+/// A polling tail over `/api/v1/logs`.
+pub struct LogStream {
+    client: Client,
+    checkpoint: LogCheckpoint,
+    since: Option<DateTime<Utc>>,
+    filter: String,
+    q: String,
+    limit: i64,
+    min_poll_interval: Duration,
+    max_poll_interval: Duration,
+    poll_interval: Duration,
+}
+
+impl LogStream {
+    fn new(client: Client, since: Option<DateTime<Utc>>, checkpoint: LogCheckpoint) -> Self {
+        LogStream {
+            client,
+            checkpoint,
+            since,
+            filter: String::new(),
+            q: String::new(),
+            limit: 0,
+            min_poll_interval: Duration::from_secs(1),
+            max_poll_interval: Duration::from_secs(60),
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+
+    /// Only return events matching this [filter expression](https://developer.okta.com/docs/reference/api/system-log/#expression-filter).
+    ///
+    /// This only applies to the first request, Okta carries it in the `next` links afterwards.
+    pub fn with_filter<S: ToString>(mut self, filter: S) -> Self {
+        self.filter = filter.to_string();
+        self
+    }
+
+    /// Only return events matching this keyword search.
+    ///
+    /// This only applies to the first request, Okta carries it in the `next` links afterwards.
+    pub fn with_query<S: ToString>(mut self, q: S) -> Self {
+        self.q = q.to_string();
+        self
+    }
+
+    /// Set the maximum number of events to return per page.
+    pub fn with_limit(mut self, limit: i64) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the bounds of the back-off used when Okta returns an empty page.
+    ///
+    /// The interval starts at `min` and doubles for each empty page until it reaches `max`.
+    pub fn with_poll_interval(mut self, min: Duration, max: Duration) -> Self {
+        self.min_poll_interval = min;
+        self.max_poll_interval = max.max(min);
+        self.poll_interval = min;
+        self
+    }
+
+    /// Get the current position of the stream.
+    pub fn checkpoint(&self) -> &LogCheckpoint {
+        &self.checkpoint
+    }
+
+    /// Wait for the next non-empty page of events.
+    ///
+    /// Empty pages are retried with a back-off, and requests are delayed until
+    /// `X-Rate-Limit-Reset` when the rate limit has been exhausted.
+    pub async fn next_page(&mut self) -> ClientResult<Vec<crate::types::LogEvent>> {
+        loop {
+            let url = self.next_url();
+            let (link, resp) = match self
+                .client
+                .request_with_links::<Vec<crate::types::LogEvent>>(
+                    http::Method::GET,
+                    &url,
+                    crate::Message::default(),
+                )
+                .await
+            {
+                Ok(r) => r,
+                Err(ClientError::HttpError {
+                    status: http::StatusCode::TOO_MANY_REQUESTS,
+                    headers,
+                    ..
+                }) => {
+                    tokio::time::sleep(
+                        rate_limit_delay(&headers).unwrap_or(self.max_poll_interval),
+                    )
+                    .await;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            let events = self.filter_seen(resp.body);
+            if let Some(link) = link {
+                self.checkpoint.next_url = link.0;
+            }
+
+            if let Some(delay) = rate_limit_delay(&resp.headers) {
+                tokio::time::sleep(delay).await;
+            }
+
+            if let Some(last) = events.last() {
+                self.checkpoint.uuid = last.uuid.to_string();
+                self.checkpoint.published = last.published;
+                self.poll_interval = self.min_poll_interval;
+                return Ok(events);
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+            self.poll_interval = (self.poll_interval * 2).min(self.max_poll_interval);
+        }
+    }
+
+    /// Turn this tail into a `Stream` of individual events.
+    ///
+    /// The stream never ends on its own, it stops after the first error.
+    pub fn into_stream(
+        self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LogEvent>> + Send {
+        futures::stream::unfold(Some(self), |state| async move {
+            let mut tail = state?;
+            match tail.next_page().await {
+                Ok(events) => Some((
+                    futures::stream::iter(events.into_iter().map(Ok).collect::<Vec<_>>()),
+                    Some(tail),
+                )),
+                Err(e) => Some((futures::stream::iter(vec![Err(e)]), None)),
+            }
+        })
+        .flatten()
+    }
+
+    fn next_url(&self) -> String {
+        if !self.checkpoint.next_url.is_empty() {
+            return self.checkpoint.next_url.to_string();
+        }
+
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !self.filter.is_empty() {
+            query_args.push(("filter".to_string(), self.filter.to_string()));
+        }
+        if self.limit > 0 {
+            query_args.push(("limit".to_string(), self.limit.to_string()));
+        }
+        if !self.q.is_empty() {
+            query_args.push(("q".to_string(), self.q.to_string()));
+        }
+        if let Some(date) = self.checkpoint.published.or(self.since) {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        query_args.push(("sortOrder".to_string(), "ASCENDING".to_string()));
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        self.client.url(&format!("/api/v1/logs?{query_}"), None)
+    }
+
+    // `since` is inclusive, so when resuming from a published time rather than a
+    // cursor we drop anything up to and including the last event we returned.
+    // Events without a published time can't be placed, so they are kept rather
+    // than risk losing them.
+    fn filter_seen(&self, events: Vec<crate::types::LogEvent>) -> Vec<crate::types::LogEvent> {
+        if self.checkpoint.uuid.is_empty() {
+            return events;
+        }
+
+        match events.iter().position(|e| e.uuid == self.checkpoint.uuid) {
+            Some(i) => events.into_iter().skip(i + 1).collect(),
+            None => events
+                .into_iter()
+                .filter(|e| e.published.is_none() || e.published >= self.checkpoint.published)
+                .collect(),
+        }
+    }
+}
+
+/// Returns how long to wait until `X-Rate-Limit-Reset` if there are no requests remaining.
+fn rate_limit_delay(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let remaining = headers
+        .get(X_RATE_LIMIT_REMAINING)
+        .and_then(|val| val.to_str().ok())
+        .and_then(|val| val.parse::<u64>().ok())?;
+    if remaining > 0 {
+        return None;
+    }
+
+    let reset = headers
+        .get(X_RATE_LIMIT_RESET)
+        .and_then(|val| val.to_str().ok())
+        .and_then(|val| val.parse::<u64>().ok())?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    Some(Duration::from_secs(reset.saturating_sub(now).max(1)))
+}
+
+impl crate::logs::Logs {
+    /// Tail the System Log, starting from `since` (or the last 15 minutes if `None`).
+    pub fn tail(&self, since: Option<DateTime<Utc>>) -> LogStream {
+        // Okta would otherwise start 7 days back.
+        let since = since.unwrap_or_else(|| {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+            DateTime::from_timestamp(now as i64 - 15 * 60, 0).unwrap()
+        });
+        LogStream::new(self.client.clone(), Some(since), LogCheckpoint::default())
+    }
+
+    /// Resume tailing the System Log from a previously persisted checkpoint.
+    pub fn resume(&self, checkpoint: LogCheckpoint) -> LogStream {
+        LogStream::new(self.client.clone(), None, checkpoint)
+    }
+}
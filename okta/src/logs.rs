@@ -87,12 +87,16 @@ impl Logs {
         until: Option<chrono::DateTime<chrono::Utc>>,
         filter: &str,
         q: &str,
+        limit: i64,
         sort_order: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::LogEvent>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -117,4 +121,46 @@ impl Logs {
             )
             .await
     }
+    /**
+     * Fetch a list of events from your Okta organization system log.
+     *
+     * This function performs a `GET` to the `/api/v1/logs` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * The Okta System Log API provides read access to your organization’s system log. This API provides more functionality than the Events API
+     */
+    pub fn get_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        limit: i64,
+        sort_order: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LogEvent>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort_order.is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(date) = until {
+            query_args.push(("until".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/logs?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
 }
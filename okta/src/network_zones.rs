@@ -63,12 +63,16 @@ impl NetworkZones {
      */
     pub async fn list_all(
         &self,
+        limit: i64,
         filter: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::NetworkZone>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/zones?{}", query_), None);
         self.client
@@ -81,6 +85,32 @@ impl NetworkZones {
             )
             .await
     }
+    /**
+     * List Network Zones.
+     *
+     * This function performs a `GET` to the `/api/v1/zones` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates network zones added to your organization with pagination. A subset of zones can be returned that match a supported filter expression or query.
+     */
+    pub fn list_stream(
+        &self,
+        limit: i64,
+        filter: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::NetworkZone>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/zones?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Add Network Zone.
      *
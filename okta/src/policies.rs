@@ -89,6 +89,36 @@ impl Policies {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Gets all policies with the specified type.
+     */
+    pub fn list_stream(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Policy>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/policies?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/policies` endpoint.
      *
@@ -329,6 +359,27 @@ impl Policies {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates all policy rules.
+     */
+    pub fn list_policy_rules_stream(
+        &self,
+        policy_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PolicyRule>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/policies/{}/rules",
+                crate::progenitor_support::encode_path(policy_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/policies/{policyId}/rules` endpoint.
      *
@@ -66,10 +66,14 @@ impl ProfileMappings {
      */
     pub async fn list_all(
         &self,
+        limit: i64,
         source_id: &str,
         target_id: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::ProfileMapping>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !source_id.is_empty() {
             query_args.push(("sourceId".to_string(), source_id.to_string()));
         }
@@ -90,6 +94,36 @@ impl ProfileMappings {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/mappings` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates Profile Mappings in your organization with pagination.
+     */
+    pub fn list_stream(
+        &self,
+        limit: i64,
+        source_id: &str,
+        target_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ProfileMapping>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !source_id.is_empty() {
+            query_args.push(("sourceId".to_string(), source_id.to_string()));
+        }
+        if !target_id.is_empty() {
+            query_args.push(("targetId".to_string(), target_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/mappings?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Get Profile Mapping.
      *
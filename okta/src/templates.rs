@@ -75,6 +75,30 @@ impl Templates {
             )
             .await
     }
+    /**
+     * List SMS Templates.
+     *
+     * This function performs a `GET` to the `/api/v1/templates/sms` endpoint.
+     *
+     * As opposed to `list_all_sms`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates custom SMS templates in your organization. A subset of templates can be returned that match a template type.
+     */
+    pub fn list_sms_stream(
+        &self,
+        template_type: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SmsTemplate>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !template_type.is_empty() {
+            query_args.push(("templateType".to_string(), template_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/templates/sms?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Add SMS Template.
      *
@@ -68,11 +68,15 @@ impl TrustedOrigins {
         &self,
         q: &str,
         filter: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::TrustedOrigin>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -90,6 +94,36 @@ impl TrustedOrigins {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/trustedOrigins` endpoint.
+     *
+     * As opposed to `list_all_origins`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_origins_stream(
+        &self,
+        q: &str,
+        filter: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TrustedOrigin>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/trustedOrigins?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/trustedOrigins` endpoint.
      *
@@ -206,15 +206,22 @@ pub struct AppLink {
     pub sort_order: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Links {}
-
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppUser {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -257,8 +264,12 @@ pub struct AppUser {
         rename = "passwordChanged"
     )]
     pub password_changed: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub profile: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -346,10 +357,20 @@ impl Status {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Application {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub accessibility: Option<ApplicationAccessibility>,
     #[serde(
@@ -393,8 +414,12 @@ pub struct Application {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub profile: serde_json::Map<String, serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<ApplicationSettings>,
     #[serde(
@@ -597,10 +622,20 @@ pub struct ApplicationCredentialsUsernameTemplate {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ApplicationGroupAssignment {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -620,8 +655,12 @@ pub struct ApplicationGroupAssignment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub priority: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub profile: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -759,10 +798,11 @@ impl ApplicationSignOnMode {
 pub struct ApplicationVisibility {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "appLinks"
     )]
-    pub app_links: bool,
+    pub app_links: std::collections::HashMap<String, bool>,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
@@ -913,8 +953,13 @@ impl RoleStatus {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuthorizationServer {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -1041,10 +1086,20 @@ pub struct AuthorizationServerCredentialsSigningConfig {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuthorizationServerPolicy {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conditions: Option<PolicyRuleConditions>,
     #[serde(
@@ -1332,8 +1387,13 @@ pub struct CallUserFactorProfile {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CatalogApplication {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2075,8 +2135,13 @@ impl VerificationStatus {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EventHook {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub channel: Option<EventHookChannel>,
     #[serde(
@@ -2508,8 +2573,13 @@ impl FactorType {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Feature {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2647,10 +2717,20 @@ pub struct ResetPasswordToken {
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Group {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -2991,8 +3071,13 @@ impl IdentityProviderType {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IdentityProvider {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -3036,10 +3121,20 @@ pub struct IdentityProvider {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IdentityProviderApplicationUser {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3066,8 +3161,12 @@ pub struct IdentityProviderApplicationUser {
         rename = "lastUpdated"
     )]
     pub last_updated: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub profile: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3241,8 +3340,13 @@ pub struct IdentityProviderPolicyRuleCondition {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InlineHook {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub channel: Option<InlineHookChannel>,
     #[serde(
@@ -3363,6 +3467,9 @@ pub struct InlineHookChannelConfigHeaders {
     pub value: String,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ResponseLinks {}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InlineHookResponse {
     #[serde(
@@ -3492,8 +3599,12 @@ pub struct IonField {
         rename = "type"
     )]
     pub type_: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub value: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub value: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -3562,8 +3673,13 @@ pub struct IonForm {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct JsonWebKey {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3694,8 +3810,13 @@ pub struct LifecycleExpirationPolicyRuleCondition {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LinkedObject {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub associated: Option<LinkedObjectDetails>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3763,8 +3884,12 @@ pub struct LogActor {
         rename = "alternateId"
     )]
     pub alternate_id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub detail: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub detail: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4008,8 +4133,13 @@ impl LogCredentialType {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LogDebugContext {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "debugData")]
-    pub debug_data: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "debugData"
+    )]
+    pub debug_data: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4294,10 +4424,11 @@ pub struct LogTarget {
     pub alternate_id: String,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "detailEntry"
     )]
-    pub detail_entry: Option<Links>,
+    pub detail_entry: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4322,8 +4453,12 @@ pub struct LogTarget {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LogTransaction {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub detail: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub detail: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4407,8 +4542,13 @@ pub struct MdmEnrollmentPolicyRuleCondition {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct NetworkZone {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -4720,8 +4860,13 @@ impl ValueType {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OAuth2Claim {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
@@ -4775,8 +4920,13 @@ pub struct OAuth2ClaimConditions {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OAuth2Client {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4836,10 +4986,20 @@ impl OAuth2RefreshTokenStatus {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OAuth2RefreshToken {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5012,10 +5172,20 @@ pub struct OAuth2Scope {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OAuth2ScopeConsentGrant {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5113,10 +5283,20 @@ pub struct OAuth2ScopesMediationPolicyRuleCondition {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OAuth2Token {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6323,10 +6503,20 @@ pub struct PlatformPolicyRuleCondition {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Policy {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conditions: Option<PolicyRuleConditions>,
     #[serde(
@@ -6856,16 +7046,25 @@ pub struct PolicyUserNameTemplate {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProfileMapping {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<ProfileMappingProperty>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub properties: std::collections::HashMap<String, ProfileMappingProperty>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<ProfileMappingSource>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -6885,13 +7084,18 @@ pub struct ProfileMappingProperty {
         skip_serializing_if = "Option::is_none",
         rename = "pushStatus"
     )]
-    pub push_status: Option<Links>,
+    pub push_status: Option<ResponseLinks>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProfileMappingSource {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7488,10 +7692,20 @@ pub struct RiskScorePolicyRuleCondition {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Role {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -8080,8 +8294,13 @@ pub struct SecurityQuestionUserFactor {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Session {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -8348,7 +8567,7 @@ pub struct SmsTemplate {
     )]
     pub template: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub translations: Option<Links>,
+    pub translations: Option<ResponseLinks>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<SmsTemplateType>,
 }
@@ -8610,8 +8829,13 @@ pub struct TempPassword {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ThreatInsightConfiguration {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8695,8 +8919,13 @@ pub struct TotpUserFactor {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TrustedOrigin {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -8764,10 +8993,20 @@ pub struct U2FUserFactor {
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct User {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -8860,10 +9099,20 @@ pub struct UserCredentials {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserFactor {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -9393,8 +9642,13 @@ pub struct UserSchema {
         rename = "$schema"
     )]
     pub schema: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9517,7 +9771,7 @@ pub struct UserSchemaAttribute {
     )]
     pub required: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub scope: Option<Links>,
+    pub scope: Option<ResponseLinks>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9527,7 +9781,7 @@ pub struct UserSchemaAttribute {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<UserSchemaAttributeType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub union: Option<Links>,
+    pub union: Option<ResponseLinks>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9888,8 +10142,12 @@ pub struct UserSchemaPublic {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub properties: std::collections::HashMap<String, UserSchemaAttribute>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -9962,8 +10220,13 @@ pub struct UserStatusPolicyRuleCondition {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserType {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -10138,10 +10401,20 @@ impl FactorResult {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct VerifyUserFactorResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: serde_json::Map<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: serde_json::Map<String, serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -69,6 +69,27 @@ impl UserFactors {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors` endpoint.
+     *
+     * As opposed to `list_all_factors`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates all the enrolled factors for the specified user
+     */
+    pub fn list_factors_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserFactor>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/factors",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Enroll Factor.
      *
@@ -186,6 +207,27 @@ impl UserFactors {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/catalog` endpoint.
+     *
+     * As opposed to `list_all_supported_factors`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates all the supported factors that can be enrolled for the specified user
+     */
+    pub fn list_supported_factors_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserFactor>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/factors/catalog",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
      *
@@ -244,6 +286,27 @@ impl UserFactors {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
+     *
+     * As opposed to `list_all_supported_security_questions`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Enumerates all available security questions for a user's `question` factor
+     */
+    pub fn list_supported_security_questions_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SecurityQuestion>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/factors/questions",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/{factorId}` endpoint.
      *
@@ -47,6 +47,20 @@ impl UserTypes {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/types/user` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Fetches all User Types in your org
+     */
+    pub fn list_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserType>> + Unpin + Send + use<>
+    {
+        let url = self.client.url("/api/v1/meta/types/user", None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/meta/types/user` endpoint.
      *
@@ -84,6 +84,7 @@ impl Users {
     pub async fn list_all(
         &self,
         q: &str,
+        limit: i64,
         filter: &str,
         search: &str,
         sort_by: &str,
@@ -93,6 +94,9 @@ impl Users {
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -117,6 +121,47 @@ impl Users {
             )
             .await
     }
+    /**
+     * List Users.
+     *
+     * This function performs a `GET` to the `/api/v1/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists users in your organization with pagination in most cases.  A subset of users can be returned that match a supported filter expression or search criteria.
+     */
+    pub fn list_stream(
+        &self,
+        q: &str,
+        limit: i64,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + Unpin + Send + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        if !sort_by.is_empty() {
+            query_args.push(("sortBy".to_string(), sort_by.to_string()));
+        }
+        if !sort_order.is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/users?{}", query_), None);
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Create User.
      *
@@ -404,6 +449,29 @@ impl Users {
             )
             .await
     }
+    /**
+     * Get Assigned App Links.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/appLinks` endpoint.
+     *
+     * As opposed to `list_all_app_links`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Fetches appLinks for all direct or indirect (via group membership) assigned applications.
+     */
+    pub fn list_app_links_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AppLink>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/appLinks",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
      *
@@ -462,6 +530,27 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
+     *
+     * As opposed to `list_all_clients`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists all client resources for which the specified user has grants or tokens.
+     */
+    pub fn list_clients_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Client>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/clients",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
      *
@@ -525,11 +614,15 @@ impl Users {
         user_id: &str,
         client_id: &str,
         expand: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -550,6 +643,42 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants_for_and_clients`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists all grants for a specified user and client
+     */
+    pub fn list_grants_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>>
+    + Unpin
+    + Send
+    + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/clients/{}/grants?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
      *
@@ -646,11 +775,15 @@ impl Users {
         user_id: &str,
         client_id: &str,
         expand: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -671,6 +804,40 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists all refresh tokens issued for the specified User and Client.
+     */
+    pub fn list_refresh_tokens_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2RefreshToken>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/clients/{}/tokens?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
      *
@@ -963,11 +1130,15 @@ impl Users {
         user_id: &str,
         scope_id: &str,
         expand: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
         if !scope_id.is_empty() {
             query_args.push(("scopeId".to_string(), scope_id.to_string()));
         }
@@ -990,6 +1161,44 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists all grants for the specified user
+     */
+    pub fn list_grants_stream(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>>
+    + Unpin
+    + Send
+    + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !scope_id.is_empty() {
+            query_args.push(("scopeId".to_string(), scope_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/grants?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/grants` endpoint.
      *
@@ -1153,6 +1362,28 @@ impl Users {
             )
             .await
     }
+    /**
+     * Get Member Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/groups` endpoint.
+     *
+     * As opposed to `list_all_groups`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Fetches the groups of which the user is a member.
+     */
+    pub fn list_groups_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + Send + use<> {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/groups",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Listing IdPs associated with a user.
      *
@@ -1215,6 +1446,29 @@ impl Users {
             )
             .await
     }
+    /**
+     * Listing IdPs associated with a user.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/idps` endpoint.
+     *
+     * As opposed to `list_all_identity_providers`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists the IdPs associated with the user.
+     */
+    pub fn list_identity_providers_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProvider>> + Unpin + Send + use<>
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/idps",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * Activate User.
      *
@@ -1573,7 +1827,7 @@ impl Users {
         relationship_name: &str,
         after: &str,
         limit: i64,
-    ) -> ClientResult<crate::Response<Vec<crate::types::Links>>> {
+    ) -> ClientResult<crate::Response<Vec<crate::types::ResponseLinks>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -1612,12 +1866,19 @@ impl Users {
         &self,
         user_id: &str,
         relationship_name: &str,
-    ) -> ClientResult<crate::Response<Vec<crate::types::Links>>> {
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ResponseLinks>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/api/v1/users/{}/linkedObjects/{}",
+                "/api/v1/users/{}/linkedObjects/{}?{}",
                 crate::progenitor_support::encode_path(user_id),
                 crate::progenitor_support::encode_path(relationship_name),
+                query_
             ),
             None,
         );
@@ -1631,6 +1892,36 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
+     *
+     * As opposed to `get_all_linked_objects_fors`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Get linked objects for a user, relationshipName can be a primary or associated relationship name
+     */
+    pub fn get_linked_objects_fors_stream(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ResponseLinks>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/linkedObjects/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(relationship_name),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
      *
@@ -1737,6 +2028,33 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles_fors`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists all roles assigned to a user.
+     */
+    pub fn list_assigned_roles_fors_stream(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Role>> + Unpin + Send + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/roles?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/users/{userId}/roles` endpoint.
      *
@@ -1869,12 +2187,19 @@ impl Users {
         &self,
         user_id: &str,
         role_id: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/api/v1/users/{}/roles/{}/targets/catalog/apps",
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps?{}",
                 crate::progenitor_support::encode_path(user_id),
                 crate::progenitor_support::encode_path(role_id),
+                query_
             ),
             None,
         );
@@ -1888,6 +2213,36 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Lists all App targets for an `APP_ADMIN` Role assigned to a User. This methods return list may include full Applications or Instances. The response for an instance will have an `ID` value, while Application will not have an ID.
+     */
+    pub fn list_application_targets_for_administrator_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CatalogApplication>> + Unpin + Send + use<>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
      *
@@ -2132,12 +2487,19 @@ impl Users {
         &self,
         user_id: &str,
         role_id: &str,
+        limit: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/api/v1/users/{}/roles/{}/targets/groups",
+                "/api/v1/users/{}/roles/{}/targets/groups?{}",
                 crate::progenitor_support::encode_path(user_id),
                 crate::progenitor_support::encode_path(role_id),
+                query_
             ),
             None,
         );
@@ -2151,6 +2513,35 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_all_group_targets_for_roles`, this function returns a stream of the items on every page, the next page is only fetched once the current one has been consumed.
+     *
+     * Success
+     */
+    pub fn list_group_targets_for_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + Send + use<> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/roles/{}/targets/groups?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_pages_stream(&url)
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}` endpoint.
      *
@@ -1 +1,379 @@
+use std::time::Duration;
 
+use futures::TryStreamExt;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{
+        body_string_contains, header, header_exists, method, path, query_param,
+        query_param_is_missing,
+    },
+};
+
+use okta::{
+    Client,
+    auth::{PrivateKey, ServiceApp},
+    log_stream::LogCheckpoint,
+};
+
+fn user(id: &str) -> serde_json::Value {
+    serde_json::json!({ "id": id, "status": "ACTIVE" })
+}
+
+// The page size is sent with the first request, the next links keep it.
+async fn mount_user_pages(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/users"))
+        .and(query_param_is_missing("after"))
+        .and(query_param("limit", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(vec![user("a"), user("b")])
+                .insert_header(
+                    "link",
+                    format!(
+                        "<{0}/api/v1/users?limit=2>; rel=\"self\", <{0}/api/v1/users?after=b&limit=2>; rel=\"next\"",
+                        server.uri()
+                    )
+                    .as_str(),
+                ),
+        )
+        .mount(server)
+        .await;
+
+    // The last page has no `next` link.
+    Mock::given(method("GET"))
+        .and(path("/api/v1/users"))
+        .and(query_param("after", "b"))
+        .and(query_param("limit", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(vec![user("c")])
+                .insert_header(
+                    "link",
+                    format!(
+                        "<{}/api/v1/users?after=b&limit=2>; rel=\"self\"",
+                        server.uri()
+                    )
+                    .as_str(),
+                ),
+        )
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_list_all_follows_link_headers() {
+    let server = MockServer::start().await;
+    mount_user_pages(&server).await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let users = client
+        .users()
+        .list_all("", 2, "", "", "", "")
+        .await
+        .unwrap()
+        .body;
+    let ids: Vec<_> = users.iter().map(|u| u.id.as_str()).collect();
+    assert_eq!(ids, vec!["a", "b", "c"]);
+}
+
+#[tokio::test]
+async fn test_list_stream_follows_link_headers() {
+    let server = MockServer::start().await;
+    mount_user_pages(&server).await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let users: Vec<_> = client
+        .users()
+        .list_stream("", 2, "", "", "", "")
+        .try_collect()
+        .await
+        .unwrap();
+    let ids: Vec<_> = users.iter().map(|u| u.id.as_str()).collect();
+    assert_eq!(ids, vec!["a", "b", "c"]);
+}
+
+fn log_event(uuid: &str, published: &str) -> serde_json::Value {
+    serde_json::json!({
+        "uuid": uuid,
+        "published": published,
+        "eventType": "user.session.start",
+    })
+}
+
+// A tail without a start goes back 15 minutes, not Okta's 7 days.
+fn since_is_recent(req: &wiremock::Request) -> bool {
+    req.url.query_pairs().any(|(key, value)| {
+        key == "since"
+            && chrono::DateTime::parse_from_rfc3339(&value).is_ok_and(|since| {
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs() as i64;
+                (14 * 60..=16 * 60).contains(&(now - since.timestamp()))
+            })
+    })
+}
+
+#[tokio::test]
+async fn test_log_stream_follows_next_links() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .and(query_param("sortOrder", "ASCENDING"))
+        .and(query_param_is_missing("after"))
+        .and(|req: &wiremock::Request| since_is_recent(req))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(vec![
+                    log_event("a", "2021-08-15T01:52:41Z"),
+                    log_event("b", "2021-08-15T01:52:42Z"),
+                ])
+                .insert_header(
+                    "link",
+                    format!("<{}/api/v1/logs?after=b>; rel=\"next\"", server.uri()).as_str(),
+                ),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // An empty page first, the stream should back off and poll again.
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .and(query_param("after", "b"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(Vec::<serde_json::Value>::new())
+                .insert_header(
+                    "link",
+                    format!("<{}/api/v1/logs?after=b>; rel=\"next\"", server.uri()).as_str(),
+                ),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .and(query_param("after", "b"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(vec![log_event("c", "2021-08-15T01:52:43Z")])
+                .insert_header(
+                    "link",
+                    format!("<{}/api/v1/logs?after=c>; rel=\"next\"", server.uri()).as_str(),
+                ),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let mut tail = client
+        .logs()
+        .tail(None)
+        .with_poll_interval(Duration::from_millis(10), Duration::from_millis(50));
+
+    let page = tail.next_page().await.unwrap();
+    assert_eq!(page.len(), 2);
+    assert_eq!(tail.checkpoint().uuid, "b");
+
+    let page = tail.next_page().await.unwrap();
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].uuid, "c");
+
+    let checkpoint = tail.checkpoint().clone();
+    assert_eq!(
+        checkpoint.next_url,
+        format!("{}/api/v1/logs?after=c", server.uri())
+    );
+
+    // Checkpoints round trip so a tail can be resumed.
+    let s = serde_json::to_string(&checkpoint).unwrap();
+    let resumed = client.logs().resume(serde_json::from_str(&s).unwrap());
+    assert_eq!(resumed.checkpoint(), &checkpoint);
+}
+
+#[tokio::test]
+async fn test_log_stream_keeps_events_without_a_published_time() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .and(query_param("since", "2021-08-15T01:52:42+00:00"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![
+            log_event("b", "2021-08-15T01:52:41Z"),
+            serde_json::json!({ "uuid": "c", "eventType": "user.session.start" }),
+            log_event("d", "2021-08-15T01:52:43Z"),
+        ]))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    // Resuming from a published time, as the cursor is gone.
+    let mut tail = client.logs().resume(LogCheckpoint {
+        uuid: "a".to_string(),
+        published: Some("2021-08-15T01:52:42Z".parse().unwrap()),
+        ..Default::default()
+    });
+
+    let page = tail.next_page().await.unwrap();
+    let uuids: Vec<_> = page.iter().map(|e| e.uuid.as_str()).collect();
+    assert_eq!(uuids, ["c", "d"]);
+}
+
+fn service_app_key() -> PrivateKey {
+    use p256::pkcs8::{EncodePrivateKey, LineEnding};
+
+    let key = p256::SecretKey::random(&mut rand::rngs::OsRng);
+    let pem = key.to_pkcs8_pem(LineEnding::LF).unwrap();
+    PrivateKey::from_pem(pem.as_bytes()).unwrap()
+}
+
+#[tokio::test]
+async fn test_service_app_caches_tokens_per_scope() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth2/v1/token"))
+        .and(body_string_contains("grant_type=client_credentials"))
+        .and(body_string_contains("scope=okta.users.read"))
+        .and(body_string_contains("client_assertion="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token_type": "Bearer",
+            "access_token": "users-token",
+            "expires_in": 3600,
+            "scope": "okta.users.read",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth2/v1/token"))
+        .and(body_string_contains("scope=okta.groups.read"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token_type": "Bearer",
+            "access_token": "groups-token",
+            "expires_in": 3600,
+            "scope": "okta.groups.read",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/users"))
+        .and(header("authorization", "Bearer users-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![user("a")]))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/groups"))
+        .and(header("authorization", "Bearer groups-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(Vec::<serde_json::Value>::new()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let service_app = ServiceApp::new("0oa1", service_app_key()).with_scopes(["okta.users.read"]);
+    let mut client = Client::new_with_service_app(service_app);
+    client.with_host_override(server.uri());
+
+    client
+        .users()
+        .list("", "", 0, "", "", "", "")
+        .await
+        .unwrap();
+    client
+        .users()
+        .list("", "", 0, "", "", "", "")
+        .await
+        .unwrap();
+    client
+        .with_scopes(["okta.groups.read"])
+        .groups()
+        .list("", "", "", 0, "")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_service_app_dpop_nonce() {
+    let server = MockServer::start().await;
+
+    // Okta hands out the nonce on the first token request.
+    Mock::given(method("POST"))
+        .and(path("/oauth2/v1/token"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(serde_json::json!({ "error": "use_dpop_nonce" }))
+                .insert_header("dpop-nonce", "nonce-1"),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth2/v1/token"))
+        .and(header_exists("dpop"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token_type": "DPoP",
+            "access_token": "dpop-token",
+            "expires_in": 3600,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/users"))
+        .and(header("authorization", "DPoP dpop-token"))
+        .and(header_exists("dpop"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![user("a")]))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let service_app = ServiceApp::new("0oa1", service_app_key())
+        .with_scopes(["okta.users.read"])
+        .with_dpop();
+    let mut client = Client::new_with_service_app(service_app);
+    client.with_host_override(server.uri());
+
+    client
+        .users()
+        .list("", "", 0, "", "", "", "")
+        .await
+        .unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let (_, proof) = requests[1]
+        .headers
+        .iter()
+        .find(|(name, _)| name.as_str() == "dpop")
+        .unwrap();
+    let proof = proof.last().as_str();
+    let claims = proof.split('.').nth(1).unwrap();
+    let claims: serde_json::Value = serde_json::from_slice(
+        &base64::Engine::decode(&base64::engine::general_purpose::URL_SAFE_NO_PAD, claims).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(claims["nonce"], "nonce-1");
+    assert_eq!(claims["htm"], "POST");
+}
@@ -109,6 +109,7 @@ pub mod departments;
 pub mod locations;
 pub mod receipts;
 pub mod reimbursements;
+pub mod sync;
 pub mod transactions;
 pub mod types;
 pub mod users;
@@ -47,3 +47,161 @@ fn test_deserialize() {
     println!("string_user_request = {}", s);
     assert!(true, "{}", s.contains("BUSINESS_USER"));
 }
+
+#[tokio::test]
+async fn test_reimbursements_sync_looks_back_from_the_checkpoint() {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path, query_param, query_param_is_missing},
+    };
+
+    let server = MockServer::start().await;
+    let reimbursement: serde_json::Value = serde_json::from_str(REIMBURSEMENT).unwrap();
+    let mut newer = reimbursement.clone();
+    newer["id"] = "e8c1d1e0-7f35-4b9c-a5a4-3c0f2f1b1f11".into();
+    let mut edited = newer.clone();
+    edited["amount"] = 12.5.into();
+
+    Mock::given(method("GET"))
+        .and(path("/reimbursements"))
+        .and(query_param_is_missing("from_date"))
+        .and(query_param_is_missing("start"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [reimbursement],
+            "page": {
+                "next": format!(
+                    "{}/reimbursements?start=c9a1c47d-e785-43c7-ac67-47b1979e0d2b",
+                    server.uri()
+                ),
+            },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/reimbursements"))
+        .and(query_param("start", "c9a1c47d-e785-43c7-ac67-47b1979e0d2b"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [newer],
+            "page": { "next": null },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // The reimbursement was edited since, it is within the lookback.
+    Mock::given(method("GET"))
+        .and(path("/reimbursements"))
+        .and(query_param("from_date", "2021-08-13T00:00:00+00:00"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [edited],
+            "page": { "next": null },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = ramp_api::Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let lookback = chrono::TimeDelta::days(2);
+    let first = client
+        .reimbursements()
+        .sync(&Default::default(), lookback)
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(first.changed.len(), 2);
+    assert!(first.checkpoint.synced_at.is_some());
+
+    let checkpoint = ramp_api::sync::SyncCheckpoint {
+        synced_at: Some("2021-08-15T00:00:00Z".parse().unwrap()),
+    };
+    let second = client
+        .reimbursements()
+        .sync(&checkpoint, lookback)
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(second.changed.len(), 1);
+    assert_eq!(second.changed[0].id, "e8c1d1e0-7f35-4b9c-a5a4-3c0f2f1b1f11");
+    assert_eq!(second.changed[0].amount, 12.5);
+    assert!(second.checkpoint.synced_at > checkpoint.synced_at);
+}
+
+fn transaction(id: &str, state: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "amount": 10.0,
+        "card_holder": { "first_name": "Kevin", "last_name": "Thing" },
+        "state": state,
+    })
+}
+
+#[tokio::test]
+async fn test_transactions_sync_looks_back_from_the_checkpoint() {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path, query_param, query_param_is_missing},
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/transactions"))
+        .and(query_param("order_by_date_asc", "true"))
+        .and(query_param_is_missing("from_date"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [transaction("t1", "PENDING")],
+            "page": { "next": format!("{}/transactions?start=t1", server.uri()) },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/transactions"))
+        .and(query_param("start", "t1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [transaction("t2", "PENDING")],
+            "page": { "next": null },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/transactions"))
+        .and(query_param("from_date", "2021-08-13T00:00:00+00:00"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [transaction("t2", "CLEARED")],
+            "page": { "next": null },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = ramp_api::Client::new("", "", "", "token", "");
+    client.with_host_override(server.uri());
+
+    let lookback = chrono::TimeDelta::days(2);
+    let first = client
+        .transactions()
+        .sync(&Default::default(), lookback)
+        .await
+        .unwrap()
+        .body;
+    let ids: Vec<_> = first.changed.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, ["t1", "t2"]);
+    assert!(first.checkpoint.synced_at.is_some());
+
+    let checkpoint = ramp_api::sync::SyncCheckpoint {
+        synced_at: Some("2021-08-15T00:00:00Z".parse().unwrap()),
+    };
+    let second = client
+        .transactions()
+        .sync(&checkpoint, lookback)
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(second.changed.len(), 1);
+    assert_eq!(second.changed[0].state, "CLEARED");
+    assert!(second.checkpoint.synced_at > checkpoint.synced_at);
+}
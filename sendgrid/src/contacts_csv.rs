@@ -0,0 +1,626 @@
+//! Importing and exporting contacts.
+//!
+//! Marketing Contacts are imported by uploading a CSV to the URL returned by
+//! [`Contacts::put_mc_imports`] and exported by downloading the files of a job
+//! started with [`Contacts::post_mc_export`], both jobs running in the
+//! background. [`Contacts::import_csv`], [`Contacts::import_contacts`] and
+//! [`Contacts::export_contacts`] do all of it, polling the job until it is
+//! done and passing each poll to a function to report progress with.
+//!
+//! ```ignore
+//! let contacts = client.contacts();
+//! let job = contacts
+//!     .import_contacts(&new_contacts, &[list_id], &PollOptions::default(), |job| {
+//!         println!("import {}", job.status);
+//!     })
+//!     .await?;
+//!
+//! let mut rows = contacts
+//!     .export_contacts(&request, &PollOptions::default(), |_| {})
+//!     .await?;
+//! while let Some(contact) = rows.next().await {
+//!     println!("{}", contact?.email);
+//! }
+//! ```
+//!
+//! FROM: <https://docs.sendgrid.com/api-reference/contacts/import-contacts>
+use std::{collections::BTreeMap, io::Write, time::Duration};
+
+use flate2::{
+    Compression,
+    write::{GzEncoder, MultiGzDecoder},
+};
+use futures::{Stream, StreamExt, TryStreamExt, stream};
+use serde::Deserialize;
+
+use crate::{
+    Client, ClientError, ClientResult,
+    contacts::Contacts,
+    types::{
+        ContactExport, ContactExportStatus, ContactImport, ContactRequest, FileType,
+        PostMcContactsExportsRequest, PutMcContactsImportsResponse,
+    },
+};
+
+/// The reserved fields [`Contacts::import_contacts`] imports, in order.
+const COLUMNS: [&str; 10] = [
+    "email",
+    "first_name",
+    "last_name",
+    "alternate_emails",
+    "address_line_1",
+    "address_line_2",
+    "city",
+    "state_province_region",
+    "postal_code",
+    "country",
+];
+
+/// How to poll an import or export.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PollOptions {
+    /// The delay after the first poll.
+    pub initial_delay: Duration,
+    /// The longest delay between two polls.
+    pub max_delay: Duration,
+    /// How much longer each delay is than the one before.
+    pub multiplier: f64,
+    /// How long to wait for the job, forever if `None`.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        PollOptions {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            multiplier: 1.5,
+            timeout: None,
+        }
+    }
+}
+
+impl PollOptions {
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_backoff(mut self, initial_delay: Duration, max_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self.max_delay = max_delay;
+        self
+    }
+}
+
+/// How to import a CSV.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CsvImport {
+    /// The ID of the field definition each column is imported into, or `None`
+    /// to skip the column.
+    pub field_mappings: Vec<Option<String>>,
+    /// The lists to add the contacts to.
+    pub list_ids: Vec<String>,
+    /// Whether to gzip the CSV before uploading it.
+    pub gzip: bool,
+}
+
+/// A contact read from an export.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ExportedContact {
+    pub id: String,
+    pub email: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub alternate_emails: Vec<String>,
+    pub address_line_1: String,
+    pub address_line_2: String,
+    pub city: String,
+    pub state_province_region: String,
+    pub postal_code: String,
+    pub country: String,
+    pub phone_number: String,
+    pub created_at: String,
+    pub updated_at: String,
+    /// The other columns, such as custom fields, by their lowercase header.
+    pub other_fields: BTreeMap<String, String>,
+}
+
+/// The spec leaves out the IDs of reserved fields, which imports map columns
+/// to.
+#[derive(Deserialize)]
+struct FieldDefinitions {
+    #[serde(default)]
+    reserved_fields: Vec<ReservedField>,
+}
+
+#[derive(Deserialize)]
+struct ReservedField {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    name: String,
+}
+
+impl Contacts {
+    /// Import `csv`, then poll the import until it is done.
+    ///
+    /// Returns the import once it is `completed`, or `errored` when some
+    /// contacts could not be imported, which its `errors_url` lists.
+    pub async fn import_csv<F>(
+        &self,
+        csv: Vec<u8>,
+        import: &CsvImport,
+        options: &PollOptions,
+        progress: F,
+    ) -> ClientResult<ContactImport>
+    where
+        F: FnMut(&ContactImport),
+    {
+        // Columns are mapped to a field ID or to null, which the generated
+        // request cannot hold.
+        let body = serde_json::json!({
+            "file_type": "csv",
+            "field_mappings": import.field_mappings,
+            "list_ids": import.list_ids,
+        });
+        let url = self.client.url("/marketing/contacts/imports", None);
+        let upload: PutMcContactsImportsResponse = self
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(&body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await?
+            .body;
+
+        // The upload URL is signed, it takes no API key.
+        let mut req = self
+            .client
+            .client
+            .request(reqwest::Method::PUT, &upload.upload_uri);
+        for header in &upload.upload_headers {
+            req = req.header(&header.header, &header.value);
+        }
+        let csv = if import.gzip {
+            req = req.header(reqwest::header::CONTENT_ENCODING, "gzip");
+            gzip(&csv)
+        } else {
+            csv
+        };
+        let response = req.body(csv).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ClientError::HttpError {
+                status,
+                headers: response.headers().clone(),
+                error: response.text().await.unwrap_or_default(),
+            });
+        }
+
+        self.wait_for_import(&upload.job_id, options, progress)
+            .await
+    }
+
+    /// Import `contacts` into the lists `list_ids` like
+    /// [`import_csv`](Self::import_csv), as a gzipped CSV of their reserved
+    /// fields.
+    ///
+    /// [`ContactRequest::custom_fields`] cannot hold any values, so contacts
+    /// that set it are rejected rather than imported without them; import
+    /// custom fields with [`import_csv`](Self::import_csv) instead.
+    pub async fn import_contacts<F>(
+        &self,
+        contacts: &[ContactRequest],
+        list_ids: &[String],
+        options: &PollOptions,
+        progress: F,
+    ) -> ClientResult<ContactImport>
+    where
+        F: FnMut(&ContactImport),
+    {
+        if let Some(i) = contacts.iter().position(|c| c.custom_fields.is_some()) {
+            return Err(ClientError::InvalidImport(format!(
+                "contact {i} has custom fields, which only import_csv can import"
+            )));
+        }
+
+        let url = self.client.url("/marketing/field_definitions", None);
+        let fields: FieldDefinitions = self
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?
+            .body;
+        let ids: BTreeMap<String, String> = fields
+            .reserved_fields
+            .into_iter()
+            .map(|field| (field.name, field.id))
+            .collect();
+
+        let mut csv = String::new();
+        write_row(&mut csv, &COLUMNS);
+        for contact in contacts {
+            write_row(
+                &mut csv,
+                &[
+                    &contact.email,
+                    &contact.first_name,
+                    &contact.last_name,
+                    &contact.alternate_emails.join(","),
+                    &contact.address_line_1,
+                    &contact.address_line_2,
+                    &contact.city,
+                    &contact.state_province_region,
+                    &contact.postal_code,
+                    &contact.country,
+                ],
+            );
+        }
+        let import = CsvImport {
+            field_mappings: COLUMNS
+                .iter()
+                .map(|column| ids.get(*column).cloned())
+                .collect(),
+            list_ids: list_ids.to_vec(),
+            gzip: true,
+        };
+
+        self.import_csv(csv.into_bytes(), &import, options, progress)
+            .await
+    }
+
+    /// Poll the import `job_id` until it is done, like
+    /// [`import_csv`](Self::import_csv).
+    pub async fn wait_for_import<F>(
+        &self,
+        job_id: &str,
+        options: &PollOptions,
+        mut progress: F,
+    ) -> ClientResult<ContactImport>
+    where
+        F: FnMut(&ContactImport),
+    {
+        let mut poll = Poll::new(job_id, options);
+        loop {
+            let job = self.get_marketing_import(job_id).await?.body;
+            progress(&job);
+            match job.status.as_str() {
+                "completed" | "errored" => return Ok(job),
+                "failed" => {
+                    let errors_url = job
+                        .results
+                        .map(|results| results.errors_url)
+                        .unwrap_or_default();
+                    return Err(ClientError::ContactJobFailed {
+                        id: job_id.to_string(),
+                        message: format!("errors are listed at {errors_url:?}"),
+                    });
+                }
+                _ => poll.sleep().await?,
+            }
+        }
+    }
+
+    /// Export the contacts of `request` as CSV, poll the export until it is
+    /// ready and then read its files one at a time, yielding the contacts of
+    /// each as it is downloaded.
+    pub async fn export_contacts<F>(
+        &self,
+        request: &PostMcContactsExportsRequest,
+        options: &PollOptions,
+        mut progress: F,
+    ) -> ClientResult<impl Stream<Item = ClientResult<ExportedContact>> + use<F>>
+    where
+        F: FnMut(&ContactExport),
+    {
+        let mut request = request.clone();
+        request.file_type = Some(FileType::Csv);
+        let id = self.post_mc_export(&request).await?.body.id;
+
+        let mut poll = Poll::new(&id, options);
+        let export = loop {
+            let export = self.get_mc_export(&id).await?.body;
+            progress(&export);
+            match export.status {
+                ContactExportStatus::Ready => break export,
+                ContactExportStatus::Failure => {
+                    return Err(ClientError::ContactJobFailed {
+                        id,
+                        message: export.message,
+                    });
+                }
+                _ => poll.sleep().await?,
+            }
+        };
+
+        let client = self.client.clone();
+        Ok(stream::iter(export.urls).flat_map(move |url| read_export(client.clone(), url)))
+    }
+}
+
+/// The delays between the polls of a job.
+struct Poll {
+    id: String,
+    deadline: Option<tokio::time::Instant>,
+    delay: Duration,
+    max_delay: Duration,
+    multiplier: f64,
+}
+
+impl Poll {
+    fn new(id: &str, options: &PollOptions) -> Self {
+        Poll {
+            id: id.to_string(),
+            deadline: options
+                .timeout
+                .map(|timeout| tokio::time::Instant::now() + timeout),
+            delay: options.initial_delay,
+            max_delay: options.max_delay,
+            multiplier: options.multiplier,
+        }
+    }
+
+    async fn sleep(&mut self) -> ClientResult<()> {
+        let mut delay = self.delay;
+        if let Some(deadline) = self.deadline {
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(ClientError::ContactJobTimeout {
+                    id: self.id.clone(),
+                });
+            }
+            delay = delay.min(deadline - now);
+        }
+        tokio::time::sleep(delay).await;
+        self.delay = self.delay.mul_f64(self.multiplier).min(self.max_delay);
+        Ok(())
+    }
+}
+
+/// Download a file of an export, which may be gzipped, and read its contacts.
+fn read_export(client: Client, url: String) -> impl Stream<Item = ClientResult<ExportedContact>> {
+    stream::once(async move { ExportFile::open(&client, &url).await })
+        .map_ok(|file| {
+            stream::try_unfold(file, |mut file| async move {
+                let contacts = file.read().await?;
+                ClientResult::Ok(contacts.map(|contacts| (contacts, file)))
+            })
+        })
+        .try_flatten()
+        .map_ok(|contacts| stream::iter(contacts.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+/// A file of an export being downloaded.
+struct ExportFile {
+    response: reqwest::Response,
+    /// `None` once the whole file is read.
+    body: Option<Body>,
+    csv: CsvReader,
+    headers: Option<Vec<String>>,
+}
+
+impl ExportFile {
+    async fn open(client: &Client, url: &str) -> ClientResult<Self> {
+        let response = client
+            .client
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ClientError::HttpError {
+                status,
+                headers: response.headers().clone(),
+                error: response.text().await.unwrap_or_default(),
+            });
+        }
+        Ok(ExportFile {
+            response,
+            body: Some(Body::Unknown(Vec::new())),
+            csv: CsvReader::default(),
+            headers: None,
+        })
+    }
+
+    /// Read the contacts of the next chunk of the file, or `None` at its end.
+    async fn read(&mut self) -> ClientResult<Option<Vec<ExportedContact>>> {
+        let Some(body) = &mut self.body else {
+            return Ok(None);
+        };
+        let rows = match self.response.chunk().await? {
+            Some(chunk) => {
+                let data = body.decode(&chunk)?;
+                self.csv.read(&data)
+            }
+            None => {
+                let data = body.finish()?;
+                self.body = None;
+                let mut rows = self.csv.read(&data);
+                rows.extend(self.csv.finish());
+                rows
+            }
+        };
+
+        let mut rows = rows.into_iter();
+        let headers = match &self.headers {
+            Some(headers) => headers,
+            None => match rows.next() {
+                Some(headers) => self
+                    .headers
+                    .insert(headers.iter().map(|h| h.to_lowercase()).collect()),
+                None => return Ok(Some(Vec::new())),
+            },
+        };
+        Ok(Some(rows.map(|row| to_contact(headers, row)).collect()))
+    }
+}
+
+/// The body of a file of an export, gzipped or not.
+enum Body {
+    /// Too little of it is read yet to tell.
+    Unknown(Vec<u8>),
+    Plain,
+    Gzip(MultiGzDecoder<Vec<u8>>),
+}
+
+impl Body {
+    /// Decode the next chunk of the body.
+    fn decode(&mut self, chunk: &[u8]) -> ClientResult<Vec<u8>> {
+        match self {
+            Body::Unknown(start) => {
+                start.extend_from_slice(chunk);
+                if start.len() < 2 {
+                    return Ok(Vec::new());
+                }
+                let start = std::mem::take(start);
+                *self = if start.starts_with(&[0x1f, 0x8b]) {
+                    Body::Gzip(MultiGzDecoder::new(Vec::new()))
+                } else {
+                    Body::Plain
+                };
+                self.decode(&start)
+            }
+            Body::Plain => Ok(chunk.to_vec()),
+            Body::Gzip(decoder) => {
+                decoder.write_all(chunk).map_err(invalid_gzip)?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+        }
+    }
+
+    /// Decode the rest of the body, checking the trailer of a gzip member.
+    fn finish(&mut self) -> ClientResult<Vec<u8>> {
+        match std::mem::replace(self, Body::Plain) {
+            Body::Unknown(start) => Ok(start),
+            Body::Plain => Ok(Vec::new()),
+            Body::Gzip(decoder) => decoder.finish().map_err(invalid_gzip),
+        }
+    }
+}
+
+fn invalid_gzip(e: std::io::Error) -> ClientError {
+    ClientError::InvalidExport(format!("malformed gzip: {e}"))
+}
+
+/// Read a contact from a row of an export.
+fn to_contact(headers: &[String], row: Vec<String>) -> ExportedContact {
+    let mut contact = ExportedContact::default();
+    for (header, value) in headers.iter().zip(row) {
+        match header.as_str() {
+            "contact_id" => contact.id = value,
+            "email" => contact.email = value,
+            "first_name" => contact.first_name = value,
+            "last_name" => contact.last_name = value,
+            "alternate_emails" => {
+                contact.alternate_emails = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|email| !email.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            }
+            "address_line_1" => contact.address_line_1 = value,
+            "address_line_2" => contact.address_line_2 = value,
+            "city" => contact.city = value,
+            "state_province_region" => contact.state_province_region = value,
+            "postal_code" => contact.postal_code = value,
+            "country" => contact.country = value,
+            "phone_number" => contact.phone_number = value,
+            "created_at" => contact.created_at = value,
+            "updated_at" => contact.updated_at = value,
+            _ => {
+                contact.other_fields.insert(header.to_string(), value);
+            }
+        }
+    }
+    contact
+}
+
+/// Append a row to `csv`, quoting the fields that need it.
+fn write_row(csv: &mut String, fields: &[&str]) {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            csv.push(',');
+        }
+        if field.contains(['"', ',', '\n', '\r']) {
+            csv.push('"');
+            csv.push_str(&field.replace('"', "\"\""));
+            csv.push('"');
+        } else {
+            csv.push_str(field);
+        }
+    }
+    csv.push_str("\r\n");
+}
+
+/// Splits a CSV read in chunks into its rows and fields.
+#[derive(Default)]
+struct CsvReader {
+    row: Vec<String>,
+    field: Vec<u8>,
+    quoted: bool,
+    /// Whether the last byte was a quote inside a quoted field, which either
+    /// ends the field or escapes another quote.
+    quote: bool,
+}
+
+impl CsvReader {
+    /// Read the next chunk of the CSV, returning the rows it completes.
+    fn read(&mut self, chunk: &[u8]) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        for &b in chunk {
+            if std::mem::take(&mut self.quote) {
+                if b == b'"' {
+                    self.field.push(b);
+                    continue;
+                }
+                self.quoted = false;
+            }
+            match (self.quoted, b) {
+                (true, b'"') => self.quote = true,
+                (true, b) => self.field.push(b),
+                (false, b'"') => self.quoted = true,
+                (false, b',') => self.end_field(),
+                (false, b'\r') => {}
+                (false, b'\n') => {
+                    self.end_field();
+                    rows.push(std::mem::take(&mut self.row));
+                }
+                (false, b) => self.field.push(b),
+            }
+        }
+        rows
+    }
+
+    /// The last row, if the CSV does not end with a line break.
+    fn finish(&mut self) -> Option<Vec<String>> {
+        if self.field.is_empty() && self.row.is_empty() {
+            return None;
+        }
+        self.end_field();
+        Some(std::mem::take(&mut self.row))
+    }
+
+    fn end_field(&mut self) {
+        let field = std::mem::take(&mut self.field);
+        self.row.push(String::from_utf8_lossy(&field).into_owned());
+    }
+}
+
+/// Compress `data` as a gzip member.
+fn gzip(data: &[u8]) -> Vec<u8> {
+    // Writing to a Vec cannot fail.
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
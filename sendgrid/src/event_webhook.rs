@@ -0,0 +1,282 @@
+//! Verifying and reading the Event Webhook.
+//!
+//! Once signing is enabled with
+//! [`Webhooks::patch_user_event_settings_signed`](crate::webhooks::Webhooks::patch_user_event_settings_signed),
+//! SendGrid signs the timestamp and body of every POST of the Event Webhook
+//! with ECDSA. An [`EventWebhookVerifier`] checks the signature against the
+//! public key of the account and reads the events.
+//!
+//! ```ignore
+//! let verifier = client.webhooks().get_event_verifier().await?;
+//!
+//! // In the handler of the webhook:
+//! let events = verifier.parse(
+//!     &body,
+//!     headers[SIGNATURE_HEADER].to_str()?,
+//!     headers[TIMESTAMP_HEADER].to_str()?,
+//! )?;
+//! for event in events {
+//!     if let Event::Bounce(bounce) = event {
+//!         println!("{} bounced: {}", bounce.info.email, bounce.reason);
+//!     }
+//! }
+//! ```
+//!
+//! FROM: <https://docs.sendgrid.com/for-developers/tracking-events/getting-started-event-webhook-security-features>
+use std::collections::BTreeMap;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use p256::{
+    ecdsa::{Signature, VerifyingKey, signature::Verifier},
+    pkcs8::DecodePublicKey,
+};
+use serde::{
+    Deserialize, Deserializer, Serialize,
+    de::{DeserializeOwned, Error},
+};
+
+use crate::{ClientError, ClientResult};
+
+/// The header with the signature of a POST of the Event Webhook.
+pub const SIGNATURE_HEADER: &str = "X-Twilio-Email-Event-Webhook-Signature";
+/// The header with the timestamp of a POST of the Event Webhook.
+pub const TIMESTAMP_HEADER: &str = "X-Twilio-Email-Event-Webhook-Timestamp";
+
+/// Checks that POSTs of the Event Webhook come from SendGrid.
+#[derive(Debug, Clone)]
+pub struct EventWebhookVerifier {
+    key: VerifyingKey,
+}
+
+impl EventWebhookVerifier {
+    /// A verifier for the base64 encoded `public_key` returned by
+    /// [`Webhooks::get_user_event_settings_signed`](crate::webhooks::Webhooks::get_user_event_settings_signed).
+    pub fn new(public_key: &str) -> ClientResult<Self> {
+        let der = STANDARD
+            .decode(public_key.trim())
+            .map_err(|e| ClientError::InvalidPublicKey(e.to_string()))?;
+        let key = VerifyingKey::from_public_key_der(&der)
+            .map_err(|e| ClientError::InvalidPublicKey(e.to_string()))?;
+        Ok(EventWebhookVerifier { key })
+    }
+
+    /// Check the `signature` and `timestamp` headers of a POST against its
+    /// body, `payload`, exactly as it was received.
+    pub fn verify(&self, payload: &[u8], signature: &str, timestamp: &str) -> ClientResult<()> {
+        let signature = STANDARD
+            .decode(signature.trim())
+            .ok()
+            .and_then(|der| Signature::from_der(&der).ok())
+            .ok_or(ClientError::InvalidSignature)?;
+        let mut message = timestamp.as_bytes().to_vec();
+        message.extend_from_slice(payload);
+        self.key
+            .verify(&message, &signature)
+            .map_err(|_| ClientError::InvalidSignature)
+    }
+
+    /// Check a POST like [`verify`](Self::verify) and read its events.
+    pub fn parse(
+        &self,
+        payload: &[u8],
+        signature: &str,
+        timestamp: &str,
+    ) -> ClientResult<Vec<Event>> {
+        self.verify(payload, signature, timestamp)?;
+        Ok(serde_json::from_slice(payload)?)
+    }
+}
+
+impl crate::webhooks::Webhooks {
+    /// A verifier for the public key of the signed Event Webhook.
+    pub async fn get_event_verifier(&self) -> ClientResult<EventWebhookVerifier> {
+        let response = self.get_user_event_settings_signed().await?;
+        EventWebhookVerifier::new(&response.body.public_key)
+    }
+}
+
+/// An event of the Event Webhook.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Event {
+    /// The mail was accepted and is about to be sent.
+    Processed(EventInfo),
+    /// The receiving server accepted the mail.
+    Delivered(Delivered),
+    /// A recipient opened the mail.
+    Open(Open),
+    /// A recipient clicked a link in the mail.
+    Click(Click),
+    /// The receiving server rejected the mail.
+    Bounce(Bounce),
+    /// The mail was not sent.
+    Dropped(Dropped),
+    /// A recipient marked the mail as spam.
+    Spamreport(EventInfo),
+    /// A recipient unsubscribed from all mail.
+    Unsubscribe(EventInfo),
+    /// A recipient unsubscribed from an unsubscribe group.
+    GroupUnsubscribe(GroupUnsubscribe),
+    /// An event this crate does not know, such as `deferred` or
+    /// `group_resubscribe`, as it was received.
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let name = value
+            .get("event")
+            .and_then(|name| name.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let event = match name.as_str() {
+            "processed" => event_data(value).map(Event::Processed),
+            "delivered" => event_data(value).map(Event::Delivered),
+            "open" => event_data(value).map(Event::Open),
+            "click" => event_data(value).map(Event::Click),
+            "bounce" => event_data(value).map(Event::Bounce),
+            "dropped" => event_data(value).map(Event::Dropped),
+            "spamreport" => event_data(value).map(Event::Spamreport),
+            "unsubscribe" => event_data(value).map(Event::Unsubscribe),
+            "group_unsubscribe" => event_data(value).map(Event::GroupUnsubscribe),
+            _ => return Ok(Event::Unknown(value)),
+        };
+        event.map_err(D::Error::custom)
+    }
+}
+
+/// The fields of an event other than its name, which is not one of its
+/// custom args.
+fn event_data<T: DeserializeOwned>(mut value: serde_json::Value) -> serde_json::Result<T> {
+    if let Some(fields) = value.as_object_mut() {
+        fields.remove("event");
+    }
+    serde_json::from_value(value)
+}
+
+/// What every event has.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct EventInfo {
+    /// The recipient.
+    #[serde(default)]
+    pub email: String,
+    /// When the event happened, in seconds since the Unix epoch.
+    #[serde(default)]
+    pub timestamp: i64,
+    #[serde(default)]
+    pub sg_event_id: String,
+    #[serde(default)]
+    pub sg_message_id: String,
+    #[serde(default, rename = "smtp-id")]
+    pub smtp_id: String,
+    /// The categories of the mail.
+    #[serde(default, deserialize_with = "deserialize_categories")]
+    pub category: Vec<String>,
+    /// The custom args of the mail, and any field of the event not read
+    /// into one of its own.
+    #[serde(flatten)]
+    pub custom_args: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Delivered {
+    #[serde(flatten)]
+    pub info: EventInfo,
+    /// The response of the receiving server.
+    #[serde(default)]
+    pub response: String,
+    /// The IP address the mail was sent from.
+    #[serde(default)]
+    pub ip: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Open {
+    #[serde(flatten)]
+    pub info: EventInfo,
+    #[serde(default)]
+    pub useragent: String,
+    /// The IP address of the recipient.
+    #[serde(default)]
+    pub ip: String,
+    /// Whether the mail was opened by a machine, such as Apple Mail Privacy
+    /// Protection, rather than the recipient.
+    #[serde(default)]
+    pub sg_machine_open: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Click {
+    #[serde(flatten)]
+    pub info: EventInfo,
+    /// The link clicked.
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub useragent: String,
+    /// The IP address of the recipient.
+    #[serde(default)]
+    pub ip: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Bounce {
+    #[serde(flatten)]
+    pub info: EventInfo,
+    /// Why the receiving server rejected the mail.
+    #[serde(default)]
+    pub reason: String,
+    /// The SMTP status code, such as `5.0.0`.
+    #[serde(default)]
+    pub status: String,
+    /// `bounce`, or `blocked` when the rejection is temporary.
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub bounce_classification: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Dropped {
+    #[serde(flatten)]
+    pub info: EventInfo,
+    /// Why the mail was not sent, such as `Bounced Address`.
+    #[serde(default)]
+    pub reason: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct GroupUnsubscribe {
+    #[serde(flatten)]
+    pub info: EventInfo,
+    /// The unsubscribe group.
+    #[serde(default)]
+    pub asm_group_id: i64,
+    #[serde(default)]
+    pub useragent: String,
+    /// The IP address of the recipient.
+    #[serde(default)]
+    pub ip: String,
+}
+
+/// A mail with one category has it as a string rather than in an array.
+fn deserialize_categories<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Categories {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<Categories>::deserialize(deserializer)? {
+        Some(Categories::One(category)) => vec![category],
+        Some(Categories::Many(categories)) => categories,
+        None => Vec::new(),
+    })
+}
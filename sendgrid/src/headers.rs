@@ -0,0 +1,29 @@
+//! The on-behalf-of header.
+//!
+//! A parent account manages the settings, templates, suppressions and stats
+//! of its subusers by sending the `on-behalf-of` header.
+//! [`Client::on_behalf_of`] returns a client that sends it with every
+//! request, including the mail sent by [`MailOps`](crate::traits::MailOps).
+//!
+//! ```ignore
+//! let client = Client::new_from_env();
+//! let bounces = client
+//!     .on_behalf_of("subuser")
+//!     .bounces_api()
+//!     .get_suppression_bounces(0, 0)
+//!     .await?;
+//! ```
+//!
+//! FROM: <https://docs.sendgrid.com/api-reference/how-to-use-the-sendgrid-v3-api/on-behalf-of-subuser>
+use crate::Client;
+
+impl Client {
+    /// A client that acts as `subuser`, either the username of a subuser or
+    /// `account-id` followed by the ID of a customer account. `self` is left
+    /// as is.
+    pub fn on_behalf_of(&self, subuser: &str) -> Client {
+        let mut client = self.clone();
+        client.on_behalf_of = Some(subuser.to_string());
+        client
+    }
+}
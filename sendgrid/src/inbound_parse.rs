@@ -0,0 +1,272 @@
+//! Reading the Inbound Parse webhook.
+//!
+//! SendGrid POSTs the mail received by a host set up with
+//! [`SettingsInboundParse`](crate::settings_inbound_parse::SettingsInboundParse)
+//! as `multipart/form-data`. [`InboundEmail::parse`] reads it, decoding each
+//! field from the charset SendGrid says it is in and matching attachments with
+//! their file names, types and content IDs. When the host is set to send the
+//! raw mail, it is in [`InboundEmail::raw`] and the text, HTML and attachments
+//! are left empty.
+//!
+//! ```ignore
+//! let email = InboundEmail::parse(headers["content-type"].to_str()?, &body)?;
+//! println!("{} from {}", email.subject, email.envelope.from);
+//! for attachment in &email.attachments {
+//!     std::fs::write(&attachment.filename, &attachment.content)?;
+//! }
+//! ```
+//!
+//! Fields are decoded from any charset with a WHATWG label, such as
+//! `iso-8859-1` or `shift_jis`. Fields in a charset with an unknown label are
+//! read as UTF-8; malformed bytes are replaced in either case.
+//!
+//! FROM: <https://docs.sendgrid.com/for-developers/parsing-email/setting-up-the-inbound-parse-webhook>
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::{ClientError, ClientResult};
+
+/// A mail POSTed by the Inbound Parse webhook.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct InboundEmail {
+    /// The headers of the mail, one per line.
+    pub headers: String,
+    pub from: String,
+    pub to: String,
+    pub cc: String,
+    pub subject: String,
+    /// The text of the mail.
+    pub text: String,
+    /// The HTML of the mail.
+    pub html: String,
+    /// Who the mail was sent from and to over SMTP, rather than in its headers.
+    pub envelope: Envelope,
+    pub attachments: Vec<Attachment>,
+    /// The whole mail as it was received, when the host is set to send it raw.
+    pub raw: Option<Vec<u8>>,
+    pub sender_ip: String,
+    /// The result of the DKIM check, such as `{@example.com : pass}`.
+    pub dkim: String,
+    /// The result of the SPF check, such as `pass`.
+    pub spf: String,
+    /// The Spam Assassin score of the mail, when spam checking is on.
+    pub spam_score: Option<f64>,
+    pub spam_report: String,
+}
+
+/// Who a mail was sent from and to over SMTP.
+#[derive(Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Envelope {
+    #[serde(default)]
+    pub to: Vec<String>,
+    #[serde(default)]
+    pub from: String,
+}
+
+/// A file attached to a mail.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Attachment {
+    pub filename: String,
+    /// The MIME type of the file.
+    pub content_type: String,
+    /// The ID the HTML shows it with as `cid:{content_id}`, for inline files.
+    pub content_id: String,
+    pub content: Vec<u8>,
+}
+
+/// What `attachment-info` says of each attachment.
+#[derive(Deserialize, Default)]
+struct AttachmentInfo {
+    #[serde(default)]
+    filename: String,
+    #[serde(default, rename = "type")]
+    type_: String,
+    #[serde(default, rename = "content-id")]
+    content_id: String,
+}
+
+/// A field of a `multipart/form-data` body.
+struct Part<'a> {
+    name: String,
+    filename: String,
+    content_type: String,
+    content: &'a [u8],
+}
+
+impl InboundEmail {
+    /// Read a POST of the Inbound Parse webhook from its `Content-Type` header
+    /// and body.
+    pub fn parse(content_type: &str, body: &[u8]) -> ClientResult<Self> {
+        let mime: mime::Mime = content_type
+            .parse()
+            .map_err(|_| invalid(format!("content type {content_type:?}")))?;
+        if mime.essence_str() != "multipart/form-data" {
+            return Err(invalid(format!("content type {content_type:?}")));
+        }
+        let boundary = mime
+            .get_param(mime::BOUNDARY)
+            .ok_or_else(|| invalid("no boundary".to_string()))?;
+        let parts = parts(body, boundary.as_str())?;
+
+        // Which charset each field is in, such as `{"subject":"UTF-8"}`.
+        let charsets: BTreeMap<String, String> = parts
+            .iter()
+            .find(|part| part.name == "charsets")
+            .map(|part| serde_json::from_slice(part.content))
+            .transpose()?
+            .unwrap_or_default();
+        let field = |name: &str| -> String {
+            parts
+                .iter()
+                .find(|part| part.name == name)
+                .map(|part| {
+                    let charset = charsets.get(name).map(String::as_str).unwrap_or_default();
+                    decode(part.content, charset)
+                })
+                .unwrap_or_default()
+        };
+
+        let mut info: BTreeMap<String, AttachmentInfo> = BTreeMap::new();
+        let attachment_info = field("attachment-info");
+        if !attachment_info.is_empty() {
+            info = serde_json::from_str(&attachment_info)?;
+        }
+        // Inline files may only be named in `content-ids`, as
+        // `{"ii_139db99fdb5c3704":"attachment1"}`.
+        let mut content_ids: BTreeMap<String, String> = BTreeMap::new();
+        let ids = field("content-ids");
+        if !ids.is_empty() {
+            let ids: BTreeMap<String, String> = serde_json::from_str(&ids)?;
+            content_ids = ids.into_iter().map(|(id, name)| (name, id)).collect();
+        }
+        let attachments = parts
+            .iter()
+            .filter(|part| !part.filename.is_empty() && part.name != "email")
+            .map(|part| {
+                let info = info.remove(&part.name).unwrap_or_default();
+                Attachment {
+                    filename: if info.filename.is_empty() {
+                        part.filename.clone()
+                    } else {
+                        info.filename
+                    },
+                    content_type: if info.type_.is_empty() {
+                        part.content_type.clone()
+                    } else {
+                        info.type_
+                    },
+                    content_id: if info.content_id.is_empty() {
+                        content_ids.remove(&part.name).unwrap_or_default()
+                    } else {
+                        info.content_id
+                    },
+                    content: part.content.to_vec(),
+                }
+            })
+            .collect();
+
+        let envelope = field("envelope");
+        let spam_score = field("spam_score");
+        Ok(InboundEmail {
+            headers: field("headers"),
+            from: field("from"),
+            to: field("to"),
+            cc: field("cc"),
+            subject: field("subject"),
+            text: field("text"),
+            html: field("html"),
+            envelope: if envelope.is_empty() {
+                Envelope::default()
+            } else {
+                serde_json::from_str(&envelope)?
+            },
+            attachments,
+            raw: parts
+                .iter()
+                .find(|part| part.name == "email")
+                .map(|part| part.content.to_vec()),
+            sender_ip: field("sender_ip"),
+            dkim: field("dkim"),
+            spf: field("SPF"),
+            spam_score: spam_score.trim().parse().ok(),
+            spam_report: field("spam_report"),
+        })
+    }
+}
+
+fn invalid(message: String) -> ClientError {
+    ClientError::InvalidInboundEmail(message)
+}
+
+/// Split a `multipart/form-data` body into its fields.
+fn parts<'a>(body: &'a [u8], boundary: &str) -> ClientResult<Vec<Part<'a>>> {
+    let delimiter = format!("--{boundary}");
+    let mut parts = Vec::new();
+    let mut rest = match find(body, delimiter.as_bytes()) {
+        Some(start) => &body[start + delimiter.len()..],
+        None => return Err(invalid("no parts".to_string())),
+    };
+    let delimiter = format!("\r\n--{boundary}");
+    loop {
+        // The last delimiter is followed by `--`.
+        if rest.starts_with(b"--") {
+            return Ok(parts);
+        }
+        let rest_of_line = rest
+            .strip_prefix(b"\r\n")
+            .ok_or_else(|| invalid("malformed delimiter".to_string()))?;
+        let end = find(rest_of_line, delimiter.as_bytes())
+            .ok_or_else(|| invalid("unterminated part".to_string()))?;
+        parts.push(part(&rest_of_line[..end])?);
+        rest = &rest_of_line[end + delimiter.len()..];
+    }
+}
+
+/// Read the headers of a field and find its content.
+fn part(part: &[u8]) -> ClientResult<Part<'_>> {
+    let end = find(part, b"\r\n\r\n").ok_or_else(|| invalid("part without headers".to_string()))?;
+    let mut field = Part {
+        name: String::new(),
+        filename: String::new(),
+        content_type: String::new(),
+        content: &part[end + 4..],
+    };
+    for line in String::from_utf8_lossy(&part[..end]).split("\r\n") {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-disposition") {
+            for param in value.split(';').skip(1) {
+                if let Some((key, value)) = param.trim().split_once('=') {
+                    let value = value.trim_matches('"').to_string();
+                    match key.trim() {
+                        "name" => field.name = value,
+                        "filename" => field.filename = value,
+                        _ => {}
+                    }
+                }
+            }
+        } else if name.eq_ignore_ascii_case("content-type") {
+            field.content_type = value.to_string();
+        }
+    }
+    Ok(field)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Decode `bytes` from `charset`, as UTF-8 when the charset is missing or
+/// unknown.
+fn decode(bytes: &[u8], charset: &str) -> String {
+    encoding_rs::Encoding::for_label(charset.as_bytes())
+        .unwrap_or(encoding_rs::UTF_8)
+        .decode_without_bom_handling(bytes)
+        .0
+        .into_owned()
+}
@@ -79,12 +79,16 @@ pub mod contacts_api_custom_fields;
 pub mod contacts_api_lists;
 pub mod contacts_api_recipients;
 pub mod contacts_api_segments;
+pub mod contacts_csv;
 pub mod csv_ui_only;
 pub mod custom_fields;
 pub mod designs_api;
 pub mod domain_authentication;
 pub mod email_address_validation;
 pub mod email_cname_records;
+pub mod event_webhook;
+pub mod headers;
+pub mod inbound_parse;
 pub mod invalid_emails_api;
 pub mod ip_access_management;
 pub mod ip_addresses;
@@ -92,6 +96,7 @@ pub mod ip_pools;
 pub mod ip_warmup;
 pub mod link_branding;
 pub mod lists;
+pub mod mail;
 pub mod mail_send;
 pub mod marketing_campaigns_stats;
 pub mod query;
@@ -157,6 +162,31 @@ pub enum ClientError {
     /// utf8 convertion error
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+
+    /// A contacts import or export failed
+    #[error("{id}: contacts job failed: {message}")]
+    ContactJobFailed { id: String, message: String },
+    /// A contacts import or export did not finish in time
+    #[error("{id}: contacts job timed out")]
+    ContactJobTimeout { id: String },
+    /// A file of a contacts export could not be read
+    #[error("Invalid export: {0}")]
+    InvalidExport(String),
+    /// Contacts could not be imported as they are
+    #[error("Invalid import: {0}")]
+    InvalidImport(String),
+    /// A POST of the Inbound Parse webhook could not be read
+    #[error("Invalid inbound email: {0}")]
+    InvalidInboundEmail(String),
+    /// A mail is missing something or breaks one of SendGrid's limits
+    #[error("Invalid mail: {0}")]
+    InvalidMail(String),
+    /// The public key of the Event Webhook could not be read
+    #[error("Invalid public key: {0}")]
+    InvalidPublicKey(String),
+    /// A POST of the Event Webhook was not signed by SendGrid
+    #[error("Invalid signature")]
+    InvalidSignature,
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
@@ -227,6 +257,7 @@ pub struct Client {
     host: String,
     host_override: Option<String>,
     token: String,
+    on_behalf_of: Option<String>,
 
     #[cfg(feature = "middleware")]
     client: reqwest_middleware::ClientWithMiddleware,
@@ -272,6 +303,7 @@ impl Client {
                     host,
                     host_override: None,
                     token: token.to_string(),
+                    on_behalf_of: None,
 
                     client,
                 }
@@ -356,6 +388,9 @@ impl Client {
             );
         }
 
+        if let Some(on_behalf_of) = &self.on_behalf_of {
+            req = req.header("on-behalf-of", on_behalf_of);
+        }
         if let Some(auth_str) = auth {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
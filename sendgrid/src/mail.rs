@@ -0,0 +1,366 @@
+//! Building mail.
+//!
+//! [`MailBuilder`] puts together the body of a `POST` to `/mail/send`: the
+//! text and HTML of the message, its attachments, the template to render it
+//! with and the [`Personalization`] of every group of recipients. Once built,
+//! a [`Mail`] is sent with [`MailOps::send`](crate::traits::MailOps::send).
+//!
+//! ```ignore
+//! let mail = MailBuilder::new("from@example.com", "Your order")
+//!     .template("d-13b8f94fbcae4ec6b75270d6cb59f932")
+//!     .personalization(
+//!         Personalization::new("to@example.com").dynamic_template_data(&order)?,
+//!     )
+//!     .inline("logo", "logo.png", "image/png", &logo)
+//!     .build()?;
+//! client.mail_send().send(&mail).await?;
+//! ```
+//!
+//! The spec leaves the custom args, headers, substitutions and template data
+//! of a mail untyped, so they are added to the body when it is built.
+//!
+//! FROM: <https://docs.sendgrid.com/api-reference/mail-send/mail-send>
+use std::{collections::BTreeMap, path::Path};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use serde::Serialize;
+
+use crate::{
+    ClientError, ClientResult,
+    types::{
+        Attachments, CcBccEmailObject, Content, Disposition, FromEmailObject, Personalizations,
+        PostMailSendRequest, ReplyEmailObject, ReplyTo,
+    },
+};
+
+/// The most personalizations, and recipients, a mail can have.
+pub const MAX_PERSONALIZATIONS: usize = 1000;
+/// The most categories a mail can have.
+pub const MAX_CATEGORIES: usize = 10;
+/// The largest a mail can be, attachments included.
+pub const MAX_SIZE: usize = 30 * 1024 * 1024;
+
+/// A mail ready to be sent.
+#[derive(Serialize, PartialEq, Debug, Clone)]
+pub struct Mail(serde_json::Value);
+
+/// The recipients of a mail and what is specific to them.
+#[derive(Default, Debug, Clone)]
+pub struct Personalization {
+    personalization: Personalizations,
+    custom_args: BTreeMap<String, String>,
+    headers: BTreeMap<String, String>,
+    substitutions: BTreeMap<String, String>,
+    dynamic_template_data: Option<serde_json::Value>,
+}
+
+impl Personalization {
+    /// A personalization for mail sent to `email`.
+    pub fn new(email: &str) -> Self {
+        Personalization::default().to(email)
+    }
+
+    /// Also send to `email`.
+    pub fn to(mut self, email: &str) -> Self {
+        self.personalization.to.push(ReplyTo {
+            email: email.to_string(),
+            name: String::new(),
+        });
+        self
+    }
+
+    /// Send a copy to `email`.
+    pub fn cc(mut self, email: &str) -> Self {
+        self.personalization.cc.push(CcBccEmailObject {
+            email: email.to_string(),
+            name: String::new(),
+        });
+        self
+    }
+
+    /// Send a blind copy to `email`.
+    pub fn bcc(mut self, email: &str) -> Self {
+        self.personalization.bcc.push(CcBccEmailObject {
+            email: email.to_string(),
+            name: String::new(),
+        });
+        self
+    }
+
+    /// The subject for these recipients, rather than that of the mail.
+    pub fn subject(mut self, subject: &str) -> Self {
+        self.personalization.subject = subject.to_string();
+        self
+    }
+
+    /// When to send to these recipients, rather than when the mail is sent.
+    pub fn send_at(mut self, send_at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.personalization.send_at = send_at.timestamp();
+        self
+    }
+
+    /// A value carried along with the events of these recipients.
+    pub fn custom_arg(mut self, key: &str, value: &str) -> Self {
+        self.custom_args.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// A header of the mail sent to these recipients.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Replace `tag` in the subject and content with `value`. Only for mail
+    /// without a dynamic template.
+    pub fn substitution(mut self, tag: &str, value: &str) -> Self {
+        self.substitutions
+            .insert(tag.to_string(), value.to_string());
+        self
+    }
+
+    /// The data to render the dynamic template of the mail with.
+    pub fn dynamic_template_data<T: Serialize>(mut self, data: &T) -> ClientResult<Self> {
+        self.dynamic_template_data = Some(serde_json::to_value(data)?);
+        Ok(self)
+    }
+
+    fn recipients(&self) -> usize {
+        self.personalization.to.len()
+            + self.personalization.cc.len()
+            + self.personalization.bcc.len()
+    }
+}
+
+/// A builder for [`Mail`].
+#[derive(Debug, Clone)]
+pub struct MailBuilder {
+    request: PostMailSendRequest,
+    text: Option<String>,
+    html: Option<String>,
+    custom_args: BTreeMap<String, String>,
+    headers: BTreeMap<String, String>,
+    personalizations: Vec<Personalization>,
+}
+
+impl MailBuilder {
+    /// A mail sent from `email` with `subject`.
+    pub fn new(email: &str, subject: &str) -> Self {
+        MailBuilder {
+            request: PostMailSendRequest {
+                from: FromEmailObject {
+                    email: email.to_string(),
+                    name: String::new(),
+                },
+                subject: subject.to_string(),
+                ..Default::default()
+            },
+            text: None,
+            html: None,
+            custom_args: BTreeMap::new(),
+            headers: BTreeMap::new(),
+            personalizations: Vec::new(),
+        }
+    }
+
+    /// The name of the sender.
+    pub fn from_name(mut self, name: &str) -> Self {
+        self.request.from.name = name.to_string();
+        self
+    }
+
+    /// Where replies go.
+    pub fn reply_to(mut self, email: &str) -> Self {
+        self.request.reply_to = Some(ReplyEmailObject {
+            email: email.to_string(),
+            name: String::new(),
+        });
+        self
+    }
+
+    /// Send to `email`, in a personalization of its own.
+    pub fn to(self, email: &str) -> Self {
+        self.personalization(Personalization::new(email))
+    }
+
+    /// Send to the recipients of `personalization`.
+    pub fn personalization(mut self, personalization: Personalization) -> Self {
+        self.personalizations.push(personalization);
+        self
+    }
+
+    /// The plain text of the mail.
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// The HTML of the mail, shown instead of its text where it can be.
+    pub fn html(mut self, html: &str) -> Self {
+        self.html = Some(html.to_string());
+        self
+    }
+
+    /// Attach `content`, of MIME type `type_`, as `filename`.
+    pub fn attachment(mut self, filename: &str, type_: &str, content: &[u8]) -> Self {
+        self.request.attachments.push(Attachments {
+            content: STANDARD.encode(content),
+            content_id: String::new(),
+            disposition: Some(Disposition::Attachment),
+            filename: filename.to_string(),
+            type_: type_.to_string(),
+        });
+        self
+    }
+
+    /// Attach the file at `path`, of MIME type `type_`.
+    pub fn attachment_file<P: AsRef<Path>>(self, path: P, type_: &str) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read(path)?;
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Ok(self.attachment(&filename, type_, &content))
+    }
+
+    /// Attach `content` inline, such as an image the HTML shows with
+    /// `<img src="cid:{content_id}">`.
+    pub fn inline(mut self, content_id: &str, filename: &str, type_: &str, content: &[u8]) -> Self {
+        self.request.attachments.push(Attachments {
+            content: STANDARD.encode(content),
+            content_id: content_id.to_string(),
+            disposition: Some(Disposition::Inline),
+            filename: filename.to_string(),
+            type_: type_.to_string(),
+        });
+        self
+    }
+
+    /// Render the mail with the template `template_id`. The data of dynamic
+    /// templates, whose ID starts with `d-`, is set by each personalization.
+    pub fn template(mut self, template_id: &str) -> Self {
+        self.request.template_id = template_id.to_string();
+        self
+    }
+
+    /// File the mail under `category` in the stats.
+    pub fn category(mut self, category: &str) -> Self {
+        self.request.categories.push(category.to_string());
+        self
+    }
+
+    /// A value carried along with the events of every recipient.
+    pub fn custom_arg(mut self, key: &str, value: &str) -> Self {
+        self.custom_args.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// A header of the mail.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// When to send the mail, at most 72 hours from now.
+    pub fn send_at(mut self, send_at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.request.send_at = send_at.timestamp();
+        self
+    }
+
+    /// The batch to send the mail in, which can be cancelled or paused as a
+    /// whole until it is sent.
+    pub fn batch_id(mut self, batch_id: &str) -> Self {
+        self.request.batch_id = batch_id.to_string();
+        self
+    }
+
+    /// Build the mail, checking it against the limits of SendGrid.
+    pub fn build(self) -> ClientResult<Mail> {
+        let MailBuilder {
+            mut request,
+            text,
+            html,
+            custom_args,
+            headers,
+            personalizations,
+        } = self;
+
+        if personalizations.is_empty() {
+            return Err(ClientError::InvalidMail("no recipients".to_string()));
+        }
+        if personalizations.len() > MAX_PERSONALIZATIONS {
+            return Err(ClientError::InvalidMail(format!(
+                "{} personalizations, at most {MAX_PERSONALIZATIONS} are allowed",
+                personalizations.len()
+            )));
+        }
+        let recipients: usize = personalizations
+            .iter()
+            .map(Personalization::recipients)
+            .sum();
+        if recipients > MAX_PERSONALIZATIONS {
+            return Err(ClientError::InvalidMail(format!(
+                "{recipients} recipients, at most {MAX_PERSONALIZATIONS} are allowed"
+            )));
+        }
+        if request.categories.len() > MAX_CATEGORIES {
+            return Err(ClientError::InvalidMail(format!(
+                "{} categories, at most {MAX_CATEGORIES} are allowed",
+                request.categories.len()
+            )));
+        }
+        if text.is_none() && html.is_none() && request.template_id.is_empty() {
+            return Err(ClientError::InvalidMail(
+                "no content and no template".to_string(),
+            ));
+        }
+
+        // The text has to come before the HTML.
+        if let Some(text) = text {
+            request.content.push(Content {
+                type_: "text/plain".to_string(),
+                value: text,
+            });
+        }
+        if let Some(html) = html {
+            request.content.push(Content {
+                type_: "text/html".to_string(),
+                value: html,
+            });
+        }
+        request.personalizations = personalizations
+            .iter()
+            .map(|p| p.personalization.clone())
+            .collect();
+
+        let mut body = serde_json::to_value(&request)?;
+        insert_map(&mut body, "custom_args", &custom_args);
+        insert_map(&mut body, "headers", &headers);
+        if let Some(values) = body["personalizations"].as_array_mut() {
+            for (value, p) in values.iter_mut().zip(&personalizations) {
+                insert_map(value, "custom_args", &p.custom_args);
+                insert_map(value, "headers", &p.headers);
+                insert_map(value, "substitutions", &p.substitutions);
+                if let Some(data) = &p.dynamic_template_data {
+                    value["dynamic_template_data"] = data.clone();
+                }
+            }
+        }
+
+        let size = serde_json::to_vec(&body)?.len();
+        if size > MAX_SIZE {
+            return Err(ClientError::InvalidMail(format!(
+                "{size} bytes, at most {MAX_SIZE} are allowed"
+            )));
+        }
+
+        Ok(Mail(body))
+    }
+}
+
+fn insert_map(value: &mut serde_json::Value, key: &str, map: &BTreeMap<String, String>) {
+    if !map.is_empty() {
+        value[key] = serde_json::json!(map);
+    }
+}
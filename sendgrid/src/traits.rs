@@ -16,6 +16,9 @@ pub trait MailOps {
         bcc: &[String],
         from: &str,
     ) -> ClientResult<Response<()>>;
+
+    /// Send a mail put together with a [`MailBuilder`](crate::mail::MailBuilder).
+    async fn send(&self, mail: &crate::mail::Mail) -> ClientResult<Response<()>>;
 }
 
 #[async_trait::async_trait]
@@ -68,6 +71,17 @@ impl MailOps for crate::mail_send::MailSend {
         }
         mail.personalizations = vec![p];
 
+        self.post_mail(serde_json::to_vec(&mail)?).await
+    }
+
+    /// Send a mail put together with a [`MailBuilder`](crate::mail::MailBuilder).
+    async fn send(&self, mail: &crate::mail::Mail) -> ClientResult<Response<()>> {
+        self.post_mail(serde_json::to_vec(mail)?).await
+    }
+}
+
+impl crate::mail_send::MailSend {
+    async fn post_mail(&self, body: Vec<u8>) -> ClientResult<Response<()>> {
         let url = self.client.url("/mail/send", None);
         let resp = self
             .client
@@ -75,7 +89,7 @@ impl MailOps for crate::mail_send::MailSend {
                 reqwest::Method::POST,
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(&mail).unwrap())),
+                    body: Some(reqwest::Body::from(body)),
                     content_type: None,
                 },
             )
@@ -1,11 +1,23 @@
-use std::{mem, time::Duration};
+use std::{
+    io::{Read, Write},
+    mem,
+    time::Duration,
+};
 
 use wiremock::{
-    Mock, MockServer, ResponseTemplate,
-    matchers::{method, path},
+    Mock, MockServer, Request, ResponseTemplate,
+    matchers::{body_json, header, method, path},
 };
 
-use sendgrid_api::{Client, types::GetScopesResponse};
+use sendgrid_api::{
+    Client, ClientError,
+    contacts_csv::{ExportedContact, PollOptions},
+    event_webhook::{Event, EventWebhookVerifier},
+    inbound_parse::InboundEmail,
+    mail::{MailBuilder, Personalization},
+    traits::MailOps,
+    types::{ContactRequest, GetScopesResponse, PostMcContactsExportsRequest},
+};
 
 #[tokio::test]
 async fn test_uses_host_override() {
@@ -28,3 +40,542 @@ async fn test_uses_host_override() {
 
     mem::drop(server)
 }
+
+#[tokio::test]
+async fn test_on_behalf_of_is_sent_with_every_request() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/scopes"))
+        .and(header("on-behalf-of", "subuser"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(GetScopesResponse { scopes: vec![] }),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/mail/send"))
+        .and(header("on-behalf-of", "subuser"))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+    let subuser = client.on_behalf_of("subuser");
+
+    subuser.api_key_permissions().get_scopes().await.unwrap();
+    subuser
+        .mail_send()
+        .send_plain_text(
+            "Hello",
+            "Hi there",
+            &["to@example.com".to_string()],
+            &[],
+            &[],
+            "from@example.com",
+        )
+        .await
+        .unwrap();
+
+    // The parent account is left as is.
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(client.api_key_permissions().get_scopes().await.is_err());
+}
+
+#[tokio::test]
+async fn test_mail_builder() {
+    #[derive(serde::Serialize)]
+    struct Order {
+        number: u32,
+    }
+
+    let mail = MailBuilder::new("from@example.com", "Your order")
+        .from_name("Shop")
+        .html("<img src=\"cid:logo\">")
+        .text("Your order")
+        .inline("logo", "logo.png", "image/png", b"png")
+        .template("d-1")
+        .category("orders")
+        .custom_arg("shop", "1")
+        .batch_id("batch")
+        .personalization(
+            Personalization::new("to@example.com")
+                .bcc("bcc@example.com")
+                .custom_arg("customer", "2")
+                .dynamic_template_data(&Order { number: 6735 })
+                .unwrap(),
+        )
+        .to("other@example.com")
+        .build()
+        .unwrap();
+    let body = serde_json::json!({
+        "from": { "email": "from@example.com", "name": "Shop" },
+        "subject": "Your order",
+        "content": [
+            { "type": "text/plain", "value": "Your order" },
+            { "type": "text/html", "value": "<img src=\"cid:logo\">" },
+        ],
+        "attachments": [{
+            "content": "cG5n",
+            "content_id": "logo",
+            "disposition": "inline",
+            "filename": "logo.png",
+            "type": "image/png",
+        }],
+        "template_id": "d-1",
+        "categories": ["orders"],
+        "custom_args": { "shop": "1" },
+        "batch_id": "batch",
+        "personalizations": [
+            {
+                "to": [{ "email": "to@example.com" }],
+                "bcc": [{ "email": "bcc@example.com" }],
+                "custom_args": { "customer": "2" },
+                "dynamic_template_data": { "number": 6735 },
+            },
+            { "to": [{ "email": "other@example.com" }] },
+        ],
+    });
+    assert_eq!(serde_json::to_value(&mail).unwrap(), body);
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/mail/send"))
+        .and(body_json(&body))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+    client.mail_send().send(&mail).await.unwrap();
+}
+
+#[test]
+fn test_mail_builder_checks_limits() {
+    let mail = MailBuilder::new("from@example.com", "Hi").text("Hi");
+    assert!(matches!(
+        mail.clone().build(),
+        Err(ClientError::InvalidMail(_))
+    ));
+    assert!(
+        MailBuilder::new("from@example.com", "Hi")
+            .to("to@example.com")
+            .build()
+            .is_err()
+    );
+
+    let too_many = (0..1001).fold(mail.clone(), |mail, i| mail.to(&format!("{i}@example.com")));
+    assert!(too_many.build().is_err());
+    let too_large = mail.to("to@example.com").attachment(
+        "a.bin",
+        "application/octet-stream",
+        &vec![0; 23 * 1024 * 1024],
+    );
+    assert!(too_large.build().is_err());
+}
+
+#[test]
+fn test_event_webhook_is_verified_and_read() {
+    use base64::{Engine, engine::general_purpose::STANDARD};
+    use p256::{
+        ecdsa::{Signature, SigningKey, signature::Signer},
+        pkcs8::EncodePublicKey,
+    };
+
+    let key = SigningKey::from_slice(&[7; 32]).unwrap();
+    let public_key = STANDARD.encode(key.verifying_key().to_public_key_der().unwrap().as_bytes());
+    let payload = br#"[
+        {"email": "a@example.com", "timestamp": 1, "event": "delivered", "response": "250 OK", "category": "orders", "order": "6735"},
+        {"email": "a@example.com", "timestamp": 2, "event": "bounce", "reason": "550 No such user", "type": "bounce", "category": ["a", "b"]},
+        {"email": "a@example.com", "timestamp": 3, "event": "deferred", "attempt": "1"}
+    ]"#;
+    let timestamp = "1600112502";
+    let signature: Signature = key.sign(&[timestamp.as_bytes(), payload].concat());
+    let signature = STANDARD.encode(signature.to_der());
+
+    let verifier = EventWebhookVerifier::new(&public_key).unwrap();
+    let events = verifier.parse(payload, &signature, timestamp).unwrap();
+    assert_eq!(events.len(), 3);
+    match &events[0] {
+        Event::Delivered(delivered) => {
+            assert_eq!(delivered.response, "250 OK");
+            assert_eq!(delivered.info.category, vec!["orders"]);
+            assert_eq!(
+                delivered.info.custom_args,
+                [("order".to_string(), serde_json::json!("6735"))].into()
+            );
+        }
+        event => panic!("not delivered: {event:?}"),
+    }
+    match &events[1] {
+        Event::Bounce(bounce) => {
+            assert_eq!(bounce.reason, "550 No such user");
+            assert_eq!(bounce.info.category, vec!["a", "b"]);
+        }
+        event => panic!("not a bounce: {event:?}"),
+    }
+    assert!(matches!(&events[2], Event::Unknown(event) if event["attempt"] == "1"));
+
+    assert!(matches!(
+        verifier.verify(payload, &signature, "1600112503"),
+        Err(ClientError::InvalidSignature)
+    ));
+    assert!(matches!(
+        verifier.verify(b"[]", &signature, timestamp),
+        Err(ClientError::InvalidSignature)
+    ));
+    assert!(EventWebhookVerifier::new("not a key").is_err());
+}
+
+#[test]
+fn test_inbound_email_is_read() {
+    fn field(name: &str, value: &[u8]) -> Vec<u8> {
+        [
+            format!("--xYzZY\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n")
+                .as_bytes(),
+            value,
+            b"\r\n",
+        ]
+        .concat()
+    }
+
+    let body = [
+        field("headers", b"From: Caf\xe9 <a@example.com>\nSubject: Hi"),
+        field("from", b"Caf\xe9 <a@example.com>"),
+        field("subject", "Café".as_bytes()),
+        field("text", b"Cze\xb6\xe6"),
+        field("envelope", br#"{"to":["b@example.com"],"from":"a@example.com"}"#),
+        field("spam_score", b"0.1"),
+        field("SPF", b"pass"),
+        field(
+            "charsets",
+            br#"{"from":"iso-8859-1","subject":"UTF-8","text":"iso-8859-2"}"#,
+        ),
+        field(
+            "attachment-info",
+            br#"{"attachment1":{"filename":"logo.png","name":"logo.png","type":"image/png","content-id":"ii_1"}}"#,
+        ),
+        b"--xYzZY\r\nContent-Disposition: form-data; name=\"attachment1\"; filename=\"logo.png\"\r\nContent-Type: image/png\r\n\r\n\x89PNG\r\n\r\n".to_vec(),
+        b"--xYzZY--\r\n".to_vec(),
+    ]
+    .concat();
+
+    let email = InboundEmail::parse("multipart/form-data; boundary=xYzZY", &body).unwrap();
+    assert_eq!(email.from, "Café <a@example.com>");
+    assert_eq!(email.subject, "Café");
+    assert_eq!(email.text, "Cześć");
+    assert_eq!(email.envelope.to, vec!["b@example.com"]);
+    assert_eq!(email.envelope.from, "a@example.com");
+    assert_eq!(email.spam_score, Some(0.1));
+    assert_eq!(email.spf, "pass");
+    assert_eq!(email.raw, None);
+    assert_eq!(email.attachments.len(), 1);
+    assert_eq!(email.attachments[0].filename, "logo.png");
+    assert_eq!(email.attachments[0].content_type, "image/png");
+    assert_eq!(email.attachments[0].content_id, "ii_1");
+    // The content of a file can look like a line break.
+    assert_eq!(email.attachments[0].content, b"\x89PNG\r\n");
+
+    let raw = [
+        field("email", b"From: a@example.com\r\n\r\nHi"),
+        b"--xYzZY--".to_vec(),
+    ]
+    .concat();
+    let email = InboundEmail::parse("multipart/form-data; boundary=xYzZY", &raw).unwrap();
+    assert_eq!(
+        email.raw.as_deref(),
+        Some(&b"From: a@example.com\r\n\r\nHi"[..])
+    );
+    assert!(email.attachments.is_empty());
+
+    assert!(matches!(
+        InboundEmail::parse("application/json", b"{}"),
+        Err(ClientError::InvalidInboundEmail(_))
+    ));
+}
+
+fn no_delay() -> PollOptions {
+    PollOptions::default().with_backoff(Duration::ZERO, Duration::ZERO)
+}
+
+#[tokio::test]
+async fn test_contacts_are_imported() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/marketing/field_definitions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "reserved_fields": [
+                { "id": "_rf0_T", "name": "first_name", "field_type": "Text" },
+                { "id": "_rf2_T", "name": "email", "field_type": "Text" },
+            ],
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/marketing/contacts/imports"))
+        .and(body_json(serde_json::json!({
+            "file_type": "csv",
+            "field_mappings": ["_rf2_T", "_rf0_T", null, null, null, null, null, null, null, null],
+            "list_ids": ["list"],
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "job_id": "job",
+            "upload_uri": format!("{}/upload", server.uri()),
+            "upload_headers": [{ "header": "x-amz-server-side-encryption", "value": "aws:kms" }],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/upload"))
+        .and(header("x-amz-server-side-encryption", "aws:kms"))
+        .and(header("content-encoding", "gzip"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/marketing/contacts/imports/job"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "id": "job", "status": "pending" })),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/marketing/contacts/imports/job"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "job",
+            "status": "completed",
+            "results": { "created_count": 1 },
+        })))
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let contact = ContactRequest {
+        address_line_1: String::new(),
+        address_line_2: String::new(),
+        alternate_emails: vec![],
+        city: String::new(),
+        country: String::new(),
+        custom_fields: None,
+        email: "a@example.com".to_string(),
+        first_name: "Ann, \"Annie\"".to_string(),
+        last_name: String::new(),
+        postal_code: String::new(),
+        state_province_region: String::new(),
+    };
+    let mut statuses = vec![];
+    let job = client
+        .contacts()
+        .import_contacts(&[contact], &["list".to_string()], &no_delay(), |job| {
+            statuses.push(job.status.clone())
+        })
+        .await
+        .unwrap();
+    assert_eq!(statuses, vec!["pending", "completed"]);
+    assert_eq!(job.results.unwrap().created_count, 1.0);
+
+    let requests = server.received_requests().await.unwrap();
+    let upload: &Request = requests
+        .iter()
+        .find(|req| req.url.path() == "/upload")
+        .unwrap();
+    let mut csv = String::new();
+    flate2::read::GzDecoder::new(&upload.body[..])
+        .read_to_string(&mut csv)
+        .unwrap();
+    assert_eq!(
+        csv,
+        "email,first_name,last_name,alternate_emails,address_line_1,address_line_2,city,state_province_region,postal_code,country\r\n\
+         a@example.com,\"Ann, \"\"Annie\"\"\",,,,,,,,\r\n"
+    );
+}
+
+#[tokio::test]
+async fn test_contacts_with_custom_fields_are_rejected() {
+    let server = MockServer::start().await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let contact: ContactRequest = serde_json::from_value(serde_json::json!({
+        "email": "a@example.com",
+        "custom_fields": { "e1_T": "gold" },
+    }))
+    .unwrap();
+    let err = client
+        .contacts()
+        .import_contacts(&[contact], &[], &no_delay(), |_| {})
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidImport(_)), "{err}");
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_contacts_are_exported() {
+    use futures::StreamExt;
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/marketing/contacts/exports"))
+        .and(body_json(
+            serde_json::json!({ "file_type": "csv", "list_ids": ["list"] }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(202)
+                .set_body_json(serde_json::json!({ "_metadata": {}, "id": "export" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/marketing/contacts/exports/export"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "id": "export", "status": "pending" })),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/marketing/contacts/exports/export"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "export",
+            "status": "ready",
+            "urls": [format!("{}/1.csv.gz", server.uri()), format!("{}/2.csv", server.uri())],
+        })))
+        .mount(&server)
+        .await;
+
+    // A gzip member with a file name, as gzip(1) writes it.
+    let csv = b"EMAIL,FIRST_NAME,ALTERNATE_EMAILS,CONTACT_ID,VIP\na@example.com,Ann,\"b@example.com, c@example.com\",1,yes\n";
+    let mut gzipped = flate2::GzBuilder::new()
+        .filename("1.csv")
+        .write(Vec::new(), flate2::Compression::default());
+    gzipped.write_all(csv).unwrap();
+    let gzipped = gzipped.finish().unwrap();
+    Mock::given(method("GET"))
+        .and(path("/1.csv.gz"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(gzipped))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/2.csv"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string("EMAIL,CONTACT_ID\r\nd@example.com,2\r\n"),
+        )
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let request = PostMcContactsExportsRequest {
+        file_type: None,
+        list_ids: vec!["list".to_string()],
+        max_file_size: 0,
+        notifications: None,
+        segment_ids: vec![],
+    };
+    let mut polls = 0;
+    let contacts: Vec<ExportedContact> = client
+        .contacts()
+        .export_contacts(&request, &no_delay(), |_| polls += 1)
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+    assert_eq!(polls, 2);
+    assert_eq!(
+        contacts,
+        vec![
+            ExportedContact {
+                id: "1".to_string(),
+                email: "a@example.com".to_string(),
+                first_name: "Ann".to_string(),
+                alternate_emails: vec!["b@example.com".to_string(), "c@example.com".to_string()],
+                other_fields: [("vip".to_string(), "yes".to_string())].into(),
+                ..Default::default()
+            },
+            ExportedContact {
+                id: "2".to_string(),
+                email: "d@example.com".to_string(),
+                ..Default::default()
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_export_with_corrupt_gzip_fails() {
+    use futures::StreamExt;
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/marketing/contacts/exports"))
+        .respond_with(
+            ResponseTemplate::new(202)
+                .set_body_json(serde_json::json!({ "_metadata": {}, "id": "export" })),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/marketing/contacts/exports/export"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "export",
+            "status": "ready",
+            "urls": [format!("{}/1.csv.gz", server.uri())],
+        })))
+        .mount(&server)
+        .await;
+
+    let mut gzipped = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    gzipped.write_all(b"EMAIL\na@example.com\n").unwrap();
+    let mut gzipped = gzipped.finish().unwrap();
+    // Break the CRC32 of the trailer.
+    let crc = gzipped.len() - 8;
+    gzipped[crc] ^= 0xff;
+    Mock::given(method("GET"))
+        .and(path("/1.csv.gz"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(gzipped))
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let request = PostMcContactsExportsRequest {
+        file_type: None,
+        list_ids: vec![],
+        max_file_size: 0,
+        notifications: None,
+        segment_ids: vec![],
+    };
+    let results: Vec<Result<ExportedContact, ClientError>> = client
+        .contacts()
+        .export_contacts(&request, &no_delay(), |_| {})
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert!(
+        matches!(results.last(), Some(Err(ClientError::InvalidExport(_)))),
+        "{results:?}"
+    );
+}
@@ -0,0 +1,43 @@
+//! The channel header.
+//!
+//! ShipBob reads the channel to act as from the `shipbob_channel_id` header,
+//! which every write and many reads require. [`Client::with_channel_id`]
+//! returns a client that sends it, keep it around to send the header with
+//! every request or use it for a single call. Requests to endpoints that do
+//! not take a channel ignore it.
+//!
+//! ```ignore
+//! let client = Client::new_from_env();
+//! let channel = client.channels().get_own().await?;
+//! let client = client.with_channel_id(channel.id);
+//!
+//! let orders = client.orders().get_all(...).await?;
+//! ```
+//!
+//! FROM: <https://developer.shipbob.com/auth/#channels>
+use crate::{Client, ClientError, ClientResult};
+
+impl Client {
+    /// A client that acts as the channel `channel_id`. `self` is left as is.
+    pub fn with_channel_id(&self, channel_id: i64) -> Client {
+        let mut client = self.clone();
+        client.channel_id = Some(channel_id);
+        client
+    }
+}
+
+impl crate::channels::Channels {
+    /// The channel the token writes with.
+    ///
+    /// A token can read from the channels of every app it was authorized for,
+    /// but it only writes with its own, which is the first one with a
+    /// `_write` scope.
+    pub async fn get_own(&self) -> ClientResult<crate::types::Channel> {
+        self.get_page()
+            .await?
+            .body
+            .into_iter()
+            .find(|channel| channel.scopes.iter().any(|scope| scope.ends_with("_write")))
+            .ok_or(ClientError::NoChannel)
+    }
+}
@@ -71,6 +71,7 @@
 ///
 /// Applications that are granted multi-channel permissions will be able to read data from all channels that belong to a user. However, multi-channel applications will only be able to write on behalf of their own channel.
 pub mod channels;
+pub mod headers;
 /// Use the Inventory Resource to retrieve ShipBob  inventory items and quantities.
 ///
 /// An inventory item is a representation of a physical good, that may or may not have physical stock in ShipBob’s fulfillment centers. Every product will have one or more inventory items mapped to it. A bundle product (a set of products that are sold as one combined package - e.g.  gift or multi packs) is an example of a product that has  2 or more inventory items mapped to it.
@@ -184,6 +185,10 @@ pub enum ClientError {
     /// utf8 convertion error
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+
+    /// None of the channels the token can use can write
+    #[error("No channel to write with")]
+    NoChannel,
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
@@ -254,6 +259,7 @@ pub struct Client {
     host: String,
     host_override: Option<String>,
     token: String,
+    channel_id: Option<i64>,
 
     #[cfg(feature = "middleware")]
     client: reqwest_middleware::ClientWithMiddleware,
@@ -299,6 +305,7 @@ impl Client {
                     host,
                     host_override: None,
                     token: token.to_string(),
+                    channel_id: None,
 
                     client,
                 }
@@ -383,6 +390,9 @@ impl Client {
             );
         }
 
+        if let Some(channel_id) = self.channel_id {
+            req = req.header("shipbob_channel_id", channel_id);
+        }
         if let Some(auth_str) = auth {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
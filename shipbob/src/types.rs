@@ -2217,10 +2217,10 @@ pub struct OrdersShipmentLog {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * Expiration date for this lot
      */
@@ -3215,9 +3215,6 @@ pub struct ReceivingOrder {
     pub status: Option<ReceivingStatus>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Extensions {}
-
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MicrosoftAspNetCoreMvcValidationProblemDetails {
     /**
@@ -3231,12 +3228,16 @@ pub struct MicrosoftAspNetCoreMvcValidationProblemDetails {
     pub detail: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub errors: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub extensions: Option<Extensions>,
+    pub errors: std::collections::HashMap<String, Vec<String>>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub extensions: serde_json::Map<String, serde_json::Value>,
     /**
      * Name of the channel
      */
@@ -3989,8 +3990,12 @@ pub struct MicrosoftAspNetCoreMvcProblemDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub detail: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub extensions: Option<Extensions>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub extensions: serde_json::Map<String, serde_json::Value>,
     /**
      * Name of the channel
      */
@@ -1 +1,55 @@
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
 
+use shipbob::Client;
+
+#[tokio::test]
+async fn test_own_channel_is_sent_with_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/channel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            { "id": 3, "name": "Shop", "application_name": "Shopify", "scopes": ["orders_read"] },
+            { "id": 7, "name": "Me", "application_name": "SMA", "scopes": ["orders_read", "orders_write"] },
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/order/1"))
+        .and(header("shipbob_channel_id", "7"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": 1 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/order/2"))
+        .and(header("shipbob_channel_id", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": 2 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let channel = client.channels().get_own().await.unwrap();
+    assert_eq!(channel.id, 7);
+    let client = client.with_channel_id(channel.id);
+
+    assert_eq!(client.orders().get(1).await.unwrap().body.id, 1);
+    assert_eq!(
+        client
+            .with_channel_id(3)
+            .orders()
+            .get(2)
+            .await
+            .unwrap()
+            .body
+            .id,
+        2
+    );
+}
@@ -4776,8 +4776,12 @@ pub struct MigrationExchangeSuccessSchema {
     /**
      * Schema for successful response from migration.exchange method
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub user_id_map: Option<Fields>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub user_id_map: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -208,7 +208,7 @@ impl CreditNotes {
      * * `invoice: &str` -- The account's country.
      * * `lines: &[String]` -- Line items that make up the credit note.
      * * `memo: &str` -- The credit note's memo appears on the credit note PDF.
-     * * `metadata: &str` -- Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format. Individual keys can be unset by posting an empty value to them. All keys can be unset by posting an empty value to `metadata`.
+     * * `metadata: &std::collections::HashMap<String, String>` -- Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format. Individual keys can be unset by posting an empty value to them. All keys can be unset by posting an empty value to `metadata`.
      * * `out_of_band_amount: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
      * * `reason: crate::types::Reason` -- Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
      * * `refund: &str` -- ID of an existing refund to link this credit note to.
@@ -221,7 +221,7 @@ impl CreditNotes {
         invoice: &str,
         lines: &[String],
         memo: &str,
-        metadata: &str,
+        metadata: &std::collections::HashMap<String, String>,
         out_of_band_amount: i64,
         reason: crate::types::Reason,
         refund: &str,
@@ -240,6 +240,9 @@ impl CreditNotes {
         if !memo.is_empty() {
             query_args.push(("memo".to_string(), memo.to_string()));
         }
+        for (key, value) in metadata {
+            query_args.push((format!("metadata[{}]", key), value.to_string()));
+        }
         if out_of_band_amount > 0 {
             query_args.push((
                 "out_of_band_amount".to_string(),
@@ -284,7 +287,7 @@ impl CreditNotes {
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `lines: &[String]` -- Line items that make up the credit note.
      * * `memo: &str` -- The credit note's memo appears on the credit note PDF.
-     * * `metadata: &str` -- Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format. Individual keys can be unset by posting an empty value to them. All keys can be unset by posting an empty value to `metadata`.
+     * * `metadata: &std::collections::HashMap<String, String>` -- Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format. Individual keys can be unset by posting an empty value to them. All keys can be unset by posting an empty value to `metadata`.
      * * `out_of_band_amount: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
      * * `reason: crate::types::Reason` -- Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
      * * `refund: &str` -- ID of an existing refund to link this credit note to.
@@ -300,7 +303,7 @@ impl CreditNotes {
         limit: i64,
         lines: &[String],
         memo: &str,
-        metadata: &str,
+        metadata: &std::collections::HashMap<String, String>,
         out_of_band_amount: i64,
         reason: crate::types::Reason,
         refund: &str,
@@ -326,6 +329,9 @@ impl CreditNotes {
         if !memo.is_empty() {
             query_args.push(("memo".to_string(), memo.to_string()));
         }
+        for (key, value) in metadata {
+            query_args.push((format!("metadata[{}]", key), value.to_string()));
+        }
         if out_of_band_amount > 0 {
             query_args.push((
                 "out_of_band_amount".to_string(),
@@ -380,7 +386,7 @@ impl CreditNotes {
         invoice: &str,
         lines: &[String],
         memo: &str,
-        metadata: &str,
+        metadata: &std::collections::HashMap<String, String>,
         out_of_band_amount: i64,
         reason: crate::types::Reason,
         refund: &str,
@@ -399,6 +405,9 @@ impl CreditNotes {
         if !memo.is_empty() {
             query_args.push(("memo".to_string(), memo.to_string()));
         }
+        for (key, value) in metadata {
+            query_args.push((format!("metadata[{}]", key), value.to_string()));
+        }
         if out_of_band_amount > 0 {
             query_args.push((
                 "out_of_band_amount".to_string(),
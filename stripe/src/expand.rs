@@ -0,0 +1,218 @@
+//! Expanding responses.
+//!
+//! Many fields hold the ID of another object unless they are expanded, in
+//! which case they hold the object itself. [`Client::with_expand`] asks for
+//! fields to be expanded and [`Expandable`] reads such fields whichever way
+//! they were returned.
+//!
+//! ```ignore
+//! let charge = client.with_expand(&["customer"]).charges().get("ch_123").await?.body;
+//! let customer: Option<Expandable<Customer>> = (*charge.customer).map(Into::into);
+//!
+//! if let Some(customer) = customer {
+//!     // Only fetches the customer if it was not expanded.
+//!     let customer = customer.fetch(&client).await?;
+//! }
+//! ```
+//!
+//! FROM: <https://stripe.com/docs/api/expanding_objects>
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::{Client, ClientResult, types::*};
+
+impl Client {
+    /// A client that expands the fields at `paths` in every response, `self`
+    /// is left as is. Nested fields are separated by `.`, fields of the
+    /// objects of a list start with `data.`, such as `data.customer`.
+    ///
+    /// The paths are sent with every GET request the client makes, the ones
+    /// that retrieve or list objects, and Stripe rejects a request whose
+    /// response has no field at one of them. Use the returned client for the
+    /// calls the paths are for only, [`Expandable::fetch`] is the exception
+    /// and sends none. Creating or updating an object sends no paths, ask for
+    /// them in the parameters of [`Client::post_params`] instead.
+    pub fn with_expand(&self, paths: &[&str]) -> Client {
+        let mut client = self.clone();
+        client.expand = paths.iter().map(|p| p.to_string()).collect();
+        client
+    }
+}
+
+/// An object that can be retrieved by its ID.
+pub trait Object: DeserializeOwned + Send + 'static {
+    /// The path the objects of this type are retrieved from, followed by
+    /// their ID.
+    const PATH: &'static str;
+
+    fn id(&self) -> &str;
+}
+
+/// The ID of an object or, if it was expanded, the object.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(untagged)]
+pub enum Expandable<T> {
+    Id(String),
+    Object(Box<T>),
+}
+
+impl<T> Expandable<T> {
+    /// The object, `None` if only its ID was returned.
+    pub fn as_object(&self) -> Option<&T> {
+        match self {
+            Expandable::Id(_) => None,
+            Expandable::Object(o) => Some(o),
+        }
+    }
+
+    pub fn into_object(self) -> Option<T> {
+        match self {
+            Expandable::Id(_) => None,
+            Expandable::Object(o) => Some(*o),
+        }
+    }
+
+    pub fn is_expanded(&self) -> bool {
+        matches!(self, Expandable::Object(_))
+    }
+}
+
+impl<T: Object> Expandable<T> {
+    /// The ID of the object, whether or not it was expanded.
+    pub fn id(&self) -> &str {
+        match self {
+            Expandable::Id(id) => id,
+            Expandable::Object(o) => o.id(),
+        }
+    }
+
+    /// The object, retrieved with `client` if only its ID was returned. The
+    /// paths `client` expands are for other responses and are not sent.
+    pub async fn fetch(&self, client: &Client) -> ClientResult<T>
+    where
+        T: Clone,
+    {
+        match self {
+            Expandable::Id(id) => {
+                let client = client.with_expand(&[]);
+                let url = client.url(
+                    &format!("{}/{}", T::PATH, crate::progenitor_support::encode_path(id)),
+                    None,
+                );
+                let resp: crate::Response<T> = client.get(&url, crate::Message::default()).await?;
+                Ok(resp.body)
+            }
+            Expandable::Object(o) => Ok((**o).clone()),
+        }
+    }
+}
+
+macro_rules! objects {
+    ($($object:ident => $path:literal,)*) => {
+        $(
+            impl Object for $object {
+                const PATH: &'static str = $path;
+
+                fn id(&self) -> &str {
+                    &self.id
+                }
+            }
+        )*
+    };
+}
+
+objects! {
+    Account => "/v1/accounts",
+    BalanceTransaction => "/v1/balance_transactions",
+    Charge => "/v1/charges",
+    Coupon => "/v1/coupons",
+    CreditNote => "/v1/credit_notes",
+    Customer => "/v1/customers",
+    File => "/v1/files",
+    Invoice => "/v1/invoices",
+    IssuingAuthorization => "/v1/issuing/authorizations",
+    IssuingCard => "/v1/issuing/cards",
+    IssuingCardholder => "/v1/issuing/cardholders",
+    IssuingDispute => "/v1/issuing/disputes",
+    IssuingTransaction => "/v1/issuing/transactions",
+    Mandate => "/v1/mandates",
+    Order => "/v1/orders",
+    PaymentIntent => "/v1/payment_intents",
+    PaymentLink => "/v1/payment_links",
+    PaymentMethod => "/v1/payment_methods",
+    Payout => "/v1/payouts",
+    Product => "/v1/products",
+    PromotionCode => "/v1/promotion_codes",
+    Quote => "/v1/quotes",
+    Recipient => "/v1/recipients",
+    Refund => "/v1/refunds",
+    Review => "/v1/reviews",
+    SetupIntent => "/v1/setup_intents",
+    ShippingRate => "/v1/shipping_rates",
+    Sku => "/v1/skus",
+    Subscription => "/v1/subscriptions",
+    SubscriptionSchedule => "/v1/subscription_schedules",
+    TaxCode => "/v1/tax_codes",
+    TaxRate => "/v1/tax_rates",
+    TerminalLocation => "/v1/terminal/locations",
+    TestClock => "/v1/test_helpers/test_clocks",
+    Transfer => "/v1/transfers",
+}
+
+/// Conversions from the generated ID-or-object fields. Deleted objects only
+/// have their ID left.
+macro_rules! expandable {
+    ($($any_of:ident => $object:ident $([$($deleted:ident),*])?,)*) => {
+        $(
+            impl From<$any_of> for Expandable<$object> {
+                fn from(field: $any_of) -> Self {
+                    match field {
+                        $any_of::String(id) => Expandable::Id(id),
+                        $any_of::$object(o) => Expandable::Object(Box::new(o)),
+                        $($($any_of::$deleted(d) => Expandable::Id(d.id),)*)?
+                    }
+                }
+            }
+        )*
+    };
+}
+
+expandable! {
+    AccountAnyOf => Account,
+    AuthorizationAnyOf => IssuingAuthorization,
+    BalanceTransactionAnyOf => BalanceTransaction,
+    CardAnyOf => IssuingCard,
+    CardholderAnyOf => IssuingCardholder,
+    ChargeAnyOf => Charge,
+    CouponAnyOf => Coupon [DeletedCoupon],
+    CreditNoteAnyOf => CreditNote,
+    CustomerAnyOf => Customer [DeletedCustomer],
+    CustomerAnyOfData => Customer,
+    DisputeAnyOf => IssuingDispute,
+    IconAnyOf => File,
+    InvoiceAnyOf => Invoice,
+    LocationAnyOf => TerminalLocation,
+    MandateAnyOf => Mandate,
+    OrderAnyOf => Order,
+    ParentAnyOf => Sku,
+    PaymentIntentAnyOf => PaymentIntent,
+    PaymentLinkAnyOf => PaymentLink,
+    PaymentMethodAnyOf => PaymentMethod,
+    ProductAnyOf => Product [DeletedProduct],
+    PromotionCodeAnyOf => PromotionCode,
+    QuoteAnyOf => Quote,
+    QuoteInvoiceAnyOf => Invoice [DeletedInvoice],
+    RecipientAnyOf => Recipient,
+    RefundAnyOf => Refund,
+    ReversedByAnyOf => Payout,
+    ReviewAnyOf => Review,
+    ScheduleAnyOf => SubscriptionSchedule,
+    SetupIntentAnyOf => SetupIntent,
+    ShippingRateAnyOf => ShippingRate,
+    SkuProductAnyOf => Product,
+    SubscriptionAnyOf => Subscription,
+    TaxCodeAnyOf => TaxCode,
+    TaxRateAnyOf => TaxRate,
+    TestClockAnyOf => TestClock,
+    TransactionAnyOf => IssuingTransaction,
+    TransferAnyOf => Transfer,
+}
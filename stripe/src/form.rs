@@ -0,0 +1,79 @@
+//! Form-encoded request bodies.
+//!
+//! Stripe takes the parameters of a POST form-encoded, with the fields of
+//! nested objects in brackets: a `metadata` map is sent as
+//! `metadata[order_id]=6735` and the items of a list as `expand[0]=customer`.
+//! [`to_form`] encodes any serializable value that way and
+//! [`Client::post_params`] sends it, for the parameters the generated
+//! functions do not take.
+//!
+//! ```ignore
+//! let metadata: HashMap<String, String> = [("order_id".to_string(), "6735".to_string())].into();
+//! let customer: Customer = client
+//!     .post_params("/v1/customers/cus_123", &serde_json::json!({ "metadata": metadata }))
+//!     .await?
+//!     .body;
+//! ```
+//!
+//! FROM: <https://stripe.com/docs/api/metadata>
+use serde::Serialize;
+
+use crate::{Client, ClientResult};
+
+/// Encode `params`, which must serialize to an object, in the bracket
+/// notation Stripe reads. Fields that are `null` are left out.
+pub fn to_form<T: Serialize>(params: &T) -> ClientResult<String> {
+    let serde_json::Value::Object(fields) = serde_json::to_value(params)? else {
+        return Err(<serde_json::Error as serde::ser::Error>::custom(
+            "form parameters must be an object",
+        )
+        .into());
+    };
+
+    let mut pairs = Vec::new();
+    for (name, value) in fields {
+        flatten(name, value, &mut pairs);
+    }
+    Ok(serde_urlencoded::to_string(&pairs).unwrap())
+}
+
+fn flatten(name: String, value: serde_json::Value, pairs: &mut Vec<(String, String)>) {
+    match value {
+        serde_json::Value::Null => {}
+        serde_json::Value::String(s) => pairs.push((name, s)),
+        serde_json::Value::Object(fields) => {
+            for (key, value) in fields {
+                flatten(format!("{name}[{key}]"), value, pairs);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (i, value) in items.into_iter().enumerate() {
+                flatten(format!("{name}[{i}]"), value, pairs);
+            }
+        }
+        value => pairs.push((name, value.to_string())),
+    }
+}
+
+impl Client {
+    /// POST `params` form-encoded to `path`, such as `/v1/customers/cus_123`.
+    pub async fn post_params<P, Out>(
+        &self,
+        path: &str,
+        params: &P,
+    ) -> ClientResult<crate::Response<Out>>
+    where
+        P: Serialize,
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let url = self.url(path, None);
+        self.post(
+            &url,
+            crate::Message {
+                body: Some(reqwest::Body::from(to_form(params)?)),
+                content_type: Some("application/x-www-form-urlencoded".to_string()),
+            },
+        )
+        .await
+    }
+}
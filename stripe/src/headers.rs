@@ -0,0 +1,66 @@
+//! Request headers.
+//!
+//! Stripe reads a few headers on every request: the API version to use, the
+//! connected account to act as and, for POSTs, an idempotency key. Each of
+//! [`Client::with_stripe_version`], [`Client::with_stripe_account`] and
+//! [`Client::with_idempotency_key`] returns a client that sends one of them,
+//! keep it around to send the header with every request or use it for a
+//! single call.
+//!
+//! ```ignore
+//! let client = Client::new_from_env().with_stripe_version("2020-08-27");
+//!
+//! // Only this charge is created on behalf of the connected account.
+//! let charge = client
+//!     .with_stripe_account("acct_123")
+//!     .with_idempotency_key("order-6735")
+//!     .charges()
+//!     .post()
+//!     .await?;
+//! println!("{:?}", charge.request_id());
+//! ```
+//!
+//! POSTs without an idempotency key get a random one, which their retries
+//! send again so that Stripe only acts on them once.
+//!
+//! FROM: <https://stripe.com/docs/api/idempotent_requests>
+use crate::Client;
+
+impl Client {
+    /// A client that asks for `version` of the API, such as `2020-08-27`,
+    /// rather than the version set for the account. `self` is left as is.
+    pub fn with_stripe_version(&self, version: &str) -> Client {
+        let mut client = self.clone();
+        client.stripe_version = Some(version.to_string());
+        client
+    }
+
+    /// A client that makes its requests as the connected account `account`.
+    /// `self` is left as is.
+    pub fn with_stripe_account(&self, account: &str) -> Client {
+        let mut client = self.clone();
+        client.stripe_account = Some(account.to_string());
+        client
+    }
+
+    /// A client whose POSTs send `key` as their idempotency key. `self` is
+    /// left as is.
+    ///
+    /// Stripe returns the result of the first request made with a key to
+    /// every later request with it, so a key should only be used for one
+    /// call.
+    pub fn with_idempotency_key(&self, key: &str) -> Client {
+        let mut client = self.clone();
+        client.idempotency_key = Some(key.to_string());
+        client
+    }
+}
+
+impl<T> crate::Response<T> {
+    /// The ID Stripe gave the request, which their support asks for.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get("request-id")
+            .and_then(|id| id.to_str().ok())
+    }
+}
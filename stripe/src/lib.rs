@@ -86,8 +86,11 @@ pub mod disputes;
 pub mod ephemeral_keys;
 pub mod events;
 pub mod exchange_rates;
+pub mod expand;
 pub mod file_links;
 pub mod files;
+pub mod form;
+pub mod headers;
 pub mod identity;
 pub mod invoiceitems;
 pub mod invoices;
@@ -231,6 +234,10 @@ pub struct Client {
     host: String,
     host_override: Option<String>,
     token: String,
+    expand: Vec<String>,
+    stripe_version: Option<String>,
+    stripe_account: Option<String>,
+    idempotency_key: Option<String>,
 
     #[cfg(feature = "middleware")]
     client: reqwest_middleware::ClientWithMiddleware,
@@ -276,6 +283,10 @@ impl Client {
                     host,
                     host_override: None,
                     token: token.to_string(),
+                    expand: Vec::new(),
+                    stripe_version: None,
+                    stripe_account: None,
+                    idempotency_key: None,
 
                     client,
                 }
@@ -339,7 +350,12 @@ impl Client {
         uri: &str,
         message: Message,
     ) -> ClientResult<reqwest::Response> {
-        let (url, auth) = self.url_and_auth(uri).await?;
+        let (mut url, auth) = self.url_and_auth(uri).await?;
+        if method == reqwest::Method::GET {
+            for path in &self.expand {
+                url.query_pairs_mut().append_pair("expand[]", path);
+            }
+        }
         let instance = <&Client>::clone(&self);
         let mut req = instance.client.request(method.clone(), url);
         // Set the default headers.
@@ -360,6 +376,19 @@ impl Client {
             );
         }
 
+        if let Some(version) = &self.stripe_version {
+            req = req.header("Stripe-Version", version);
+        }
+        if let Some(account) = &self.stripe_account {
+            req = req.header("Stripe-Account", account);
+        }
+        if method == reqwest::Method::POST {
+            let key = self
+                .idempotency_key
+                .clone()
+                .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+            req = req.header("Idempotency-Key", key);
+        }
         if let Some(auth_str) = auth {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
@@ -397,10 +397,10 @@ pub struct Account {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -1901,10 +1901,10 @@ pub struct AlipayAccount {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -3724,10 +3724,10 @@ pub struct BankAccount {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -3903,10 +3903,10 @@ pub struct PortalConfiguration {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -4482,10 +4482,10 @@ pub struct BitcoinReceiver {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -5130,10 +5130,10 @@ pub struct Card {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * You can store multiple cards on a customer in order to charge the customer
      *  later. You can also store multiple debit cards on a recipient in order to
@@ -5905,10 +5905,10 @@ pub struct Charge {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -7185,10 +7185,10 @@ pub struct Session {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The mode of the Checkout Session.
      */
@@ -8156,10 +8156,10 @@ pub struct Coupon {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * A coupon contains information about a percent-off or amount-off discount you
      *  might want to apply to a customer. Coupons may be applied to [invoices](https://stripe.com/docs/api#invoices) or
@@ -8606,10 +8606,10 @@ pub struct CreditNote {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The account's country.
      */
@@ -9518,10 +9518,10 @@ pub struct Customer {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * This object represents a customer of your business. It lets you create recurring charges and track payments that belong to the same customer.
      *  
@@ -9976,10 +9976,10 @@ pub struct CustomerBalanceTransaction {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -11968,10 +11968,10 @@ pub struct Dispute {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -12771,10 +12771,10 @@ pub struct FeeRefund {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -13135,10 +13135,10 @@ pub struct FileLink {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -14501,10 +14501,10 @@ pub struct GelatoVerificationSession {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -15816,10 +15816,10 @@ pub struct Invoice {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * Invoices are statements of amounts owed by a customer, and are either
      *  generated one-off, or generated periodically from a subscription.
@@ -17032,10 +17032,10 @@ pub struct InvoiceItem {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -17924,10 +17924,10 @@ pub struct IssuingAuthorization {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -18304,10 +18304,10 @@ pub struct IssuingCard {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * You can [create physical or virtual cards](https://stripe.com/docs/issuing/cards) that are issued to cardholders.
      */
@@ -18528,10 +18528,10 @@ pub struct IssuingCardholder {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The account's country.
      */
@@ -18777,10 +18777,10 @@ pub struct IssuingDispute {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -18926,10 +18926,10 @@ pub struct IssuingSettlement {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -19316,10 +19316,10 @@ pub struct IssuingTransaction {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -22776,10 +22776,10 @@ pub struct LineItem {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -23591,10 +23591,10 @@ pub struct Order {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -24560,10 +24560,10 @@ pub struct PaymentIntent {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * If present, this property tells you what actions you need to take in order for your customer to fulfill a payment using the provided source.
      */
@@ -26895,10 +26895,10 @@ pub struct PaymentLink {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -28141,10 +28141,10 @@ pub struct PaymentMethod {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -32677,10 +32677,10 @@ pub struct Payout {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The account's country.
      */
@@ -33045,10 +33045,10 @@ pub struct Person {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * This is an object representing a person associated with a Stripe account.
      *  
@@ -33606,10 +33606,10 @@ pub struct PlanData {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * You can now model subscriptions more flexibly using the [Prices API](https://stripe.com/docs/api#prices). It replaces the Plans API and is backwards compatible to simplify your migration.
      *  
@@ -34350,10 +34350,10 @@ pub struct PriceData {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * Prices define the unit cost, currency, and (optional) billing cycle for both recurring and one-time purchases of products.
      *  [Products](https://stripe.com/docs/api#products) help you track inventory or provisioning, and prices help you track payment terms. Different physical goods or levels of service should be represented by products, and pricing options should be represented by prices. This approach lets you change prices without having to change your provisioning scheme.
@@ -34610,10 +34610,10 @@ pub struct Product {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The account's country.
      */
@@ -34833,10 +34833,10 @@ pub struct PromotionCode {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -35269,10 +35269,10 @@ pub struct Quote {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * A Quote is a way to model prices that you'd like to provide to a customer.
      *  Once accepted, it will automatically create an invoice, subscription or subscription schedule.
@@ -35795,10 +35795,10 @@ pub struct RadarList {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The account's country.
      */
@@ -36158,10 +36158,10 @@ pub struct Recipient {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * With `Recipient` objects, you can transfer money from your Stripe account to a
      *  third-party bank account or debit card. The API allows you to create, delete,
@@ -36485,10 +36485,10 @@ pub struct Refund {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * `Refund` objects allow you to refund a charge that has previously been created
      *  but not yet refunded. Funds will be refunded to the credit or debit card that
@@ -38068,10 +38068,10 @@ pub struct SetupIntent {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * If present, this property tells you what actions you need to take in order for your customer to continue payment setup.
      */
@@ -38803,10 +38803,10 @@ pub struct ShippingRate {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -39062,10 +39062,10 @@ pub struct Sku {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -39477,10 +39477,10 @@ pub struct SourceData {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * `Source` objects allow you to accept a variety of payment methods. They
      *  represent a customer's payment instrument, and can be used with the Stripe API
@@ -42392,10 +42392,10 @@ pub struct Subscription {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * Subscriptions allow you to charge a customer on a recurring basis.
      *  
@@ -42555,10 +42555,10 @@ pub struct SubscriptionItem {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -42844,10 +42844,10 @@ pub struct SubscriptionSchedule {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -43954,10 +43954,10 @@ pub struct TaxRate {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -44096,10 +44096,10 @@ pub struct TerminalLocation {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -44277,10 +44277,10 @@ pub struct TerminalReader {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -45396,10 +45396,10 @@ pub struct Topup {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -45641,10 +45641,10 @@ pub struct Transfer {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -45859,10 +45859,10 @@ pub struct TransferReversal {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -46299,10 +46299,10 @@ pub struct WebhookEndpoint {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -48147,7 +48147,7 @@ impl std::convert::From<GetInvoicesUpcomingDiscountsAnyOf> for Vec<Discounts> {
 
 /// All of the following types:
 ///
-/// - `String`
+/// - `std::collections::HashMap<String, String>`
 /// - `Shipping`
 ///
 /// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
@@ -48158,7 +48158,7 @@ pub enum MetadataAnyOf {
     /**
      * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
      */
-    String(String),
+    StringMap(std::collections::HashMap<String, String>),
     Shipping(Shipping),
 }
 
@@ -48170,23 +48170,23 @@ impl MetadataAnyOf {
         None
     }
 
-    pub fn string(&self) -> Option<&String> {
-        if let MetadataAnyOf::String(ref_) = self {
+    pub fn string_map(&self) -> Option<&std::collections::HashMap<String, String>> {
+        if let MetadataAnyOf::StringMap(ref_) = self {
             return Some(ref_);
         }
         None
     }
 }
 
-impl std::convert::From<String> for MetadataAnyOf {
-    fn from(f: String) -> Self {
-        MetadataAnyOf::String(f)
+impl std::convert::From<std::collections::HashMap<String, String>> for MetadataAnyOf {
+    fn from(f: std::collections::HashMap<String, String>) -> Self {
+        MetadataAnyOf::StringMap(f)
     }
 }
 
-impl std::convert::From<MetadataAnyOf> for String {
+impl std::convert::From<MetadataAnyOf> for std::collections::HashMap<String, String> {
     fn from(f: MetadataAnyOf) -> Self {
-        f.string().unwrap().clone()
+        f.string_map().unwrap().clone()
     }
 }
 
@@ -1 +1,273 @@
+use std::collections::HashMap;
 
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_string, header, method, path, query_param, query_param_is_missing},
+};
+
+use dolladollabills::{
+    Client,
+    expand::Expandable,
+    form::to_form,
+    types::{Customer, CustomerAnyOf, Reason},
+};
+
+fn customer(id: &str, email: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "object": "customer",
+        "email": email,
+        "default_source": null,
+        "test_clock": null,
+    })
+}
+
+#[tokio::test]
+async fn test_expand_is_sent_and_fields_are_read_either_way() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/charges/ch_1"))
+        .and(query_param("expand[]", "customer"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "ch_1",
+            "object": "charge",
+            "billing_details": {},
+            "refunds": {},
+            "customer": customer("cus_1", "a@example.com"),
+            "application": null,
+            "application_fee": null,
+            "balance_transaction": null,
+            "failure_balance_transaction": null,
+            "invoice": null,
+            "on_behalf_of": null,
+            "order": null,
+            "payment_intent": null,
+            "review": null,
+            "source_transfer": null,
+            "transfer": null,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/customers/cus_2"))
+        .and(query_param_is_missing("expand[]"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(customer("cus_2", "b@example.com")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("sk_test");
+    client.with_host_override(server.uri());
+
+    let expanding = client.with_expand(&["customer"]);
+    let charge = expanding.charges().get("ch_1").await.unwrap().body;
+    let customer: Expandable<Customer> = (*charge.customer).unwrap().into();
+    assert!(customer.is_expanded());
+    assert_eq!(customer.id(), "cus_1");
+    assert_eq!(customer.as_object().unwrap().email, "a@example.com");
+    assert_eq!(
+        customer.fetch(&client).await.unwrap().email,
+        "a@example.com"
+    );
+
+    let customer: Expandable<Customer> = CustomerAnyOf::String("cus_2".to_string()).into();
+    assert_eq!(customer.id(), "cus_2");
+    assert!(customer.as_object().is_none());
+    assert_eq!(
+        customer.fetch(&expanding).await.unwrap().email,
+        "b@example.com"
+    );
+}
+
+#[tokio::test]
+async fn test_metadata_is_a_map_both_ways() {
+    let server = MockServer::start().await;
+
+    let mut with_metadata = customer("cus_1", "a@example.com");
+    with_metadata["metadata"] = serde_json::json!({ "order_id": "6735", "channel": "web" });
+    Mock::given(method("GET"))
+        .and(path("/v1/customers/cus_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(with_metadata))
+        .mount(&server)
+        .await;
+    let mut null_metadata = customer("cus_2", "b@example.com");
+    null_metadata["metadata"] = serde_json::Value::Null;
+    Mock::given(method("GET"))
+        .and(path("/v1/customers/cus_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(null_metadata))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/credit_notes/preview/lines"))
+        .and(query_param("invoice", "in_1"))
+        .and(query_param("metadata[order_id]", "6735"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "object": "list",
+            "data": [],
+            "has_more": false,
+            "url": "/v1/credit_notes/preview/lines",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("sk_test");
+    client.with_host_override(server.uri());
+
+    let body = client.customers().get("cus_1").await.unwrap().body;
+    let customer = body.customer().unwrap();
+    assert_eq!(customer.metadata["order_id"], "6735");
+    assert_eq!(customer.metadata["channel"], "web");
+    let json = serde_json::to_value(customer).unwrap();
+    assert_eq!(json["metadata"]["order_id"], "6735");
+    assert_eq!(&serde_json::from_value::<Customer>(json).unwrap(), customer);
+
+    let body = client.customers().get("cus_2").await.unwrap().body;
+    let customer = body.customer().unwrap();
+    assert!(customer.metadata.is_empty());
+    assert!(
+        serde_json::to_value(customer)
+            .unwrap()
+            .get("metadata")
+            .is_none()
+    );
+
+    let metadata = [("order_id".to_string(), "6735".to_string())].into();
+    client
+        .credit_notes()
+        .get_preview_lines(
+            0,
+            0,
+            "",
+            "in_1",
+            0,
+            &[],
+            "",
+            &metadata,
+            0,
+            Reason::Noop,
+            "",
+            0,
+            "",
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_metadata_is_form_encoded_in_post_bodies() {
+    #[derive(serde::Serialize)]
+    struct Params {
+        metadata: HashMap<String, String>,
+        expand: Vec<String>,
+        description: Option<String>,
+    }
+
+    let params = Params {
+        metadata: [("order_id".to_string(), "6735".to_string())].into(),
+        expand: vec!["default_source".to_string()],
+        description: None,
+    };
+    assert_eq!(
+        to_form(&params).unwrap(),
+        "expand%5B0%5D=default_source&metadata%5Border_id%5D=6735"
+    );
+    assert!(to_form(&"metadata").is_err());
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/customers/cus_1"))
+        .and(query_param_is_missing("expand[]"))
+        .and(header("content-type", "application/x-www-form-urlencoded"))
+        .and(body_string(
+            "expand%5B0%5D=default_source&metadata%5Border_id%5D=6735",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(customer("cus_1", "a@example.com")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("sk_test");
+    client.with_host_override(server.uri());
+
+    // The paths of with_expand are only sent with GET requests, a POST asks
+    // for them in its parameters.
+    let customer: Customer = client
+        .with_expand(&["default_source"])
+        .post_params("/v1/customers/cus_1", &params)
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(customer.id, "cus_1");
+}
+
+#[tokio::test]
+async fn test_headers_are_sent_and_retried_posts_keep_their_idempotency_key() {
+    let server = MockServer::start().await;
+
+    let coupon = serde_json::json!({ "id": "co_1", "object": "coupon" });
+    Mock::given(method("POST"))
+        .and(path("/v1/coupons/co_1"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/coupons/co_1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Request-Id", "req_1")
+                .set_body_json(&coupon),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/coupons/co_1"))
+        .and(header("Stripe-Version", "2020-08-27"))
+        .and(header("Stripe-Account", "acct_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&coupon))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("sk_test");
+    client.with_host_override(server.uri());
+    let client = client.with_stripe_version("2020-08-27");
+
+    let coupon = client.coupons().post_coupons("co_1").await.unwrap();
+    assert_eq!(coupon.request_id(), Some("req_1"));
+    client
+        .with_idempotency_key("coupon-co_1")
+        .coupons()
+        .post_coupons("co_1")
+        .await
+        .unwrap();
+    client
+        .with_stripe_account("acct_1")
+        .coupons()
+        .get("co_1")
+        .await
+        .unwrap();
+
+    let keys: Vec<String> = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|req| {
+            req.headers
+                .get(&"Idempotency-Key".into())
+                .map(|key| key.as_str().to_string())
+                .unwrap_or_default()
+        })
+        .collect();
+    // The failed POST and its retry, then the POST with a key and the GET.
+    assert_eq!(keys.len(), 4);
+    assert!(!keys[0].is_empty());
+    assert_eq!(keys[0], keys[1]);
+    assert_eq!(keys[2], "coupon-co_1");
+    assert_eq!(keys[3], "");
+}
@@ -260,6 +260,8 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
+    account_id: String,
+    token_endpoint: Option<String>,
 
     auto_refresh: bool,
     #[cfg(feature = "middleware")]
@@ -374,6 +376,8 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    account_id: String::new(),
+                    token_endpoint: None,
 
                     auto_refresh: false,
                     client,
@@ -515,6 +519,10 @@ impl Client {
     /// Refresh an access token from a refresh token. Client must have a refresh token
     /// for this to work.
     pub async fn refresh_access_token(&self) -> ClientResult<AccessToken> {
+        if !self.account_id.is_empty() {
+            return self.get_account_credentials_token().await;
+        }
+
         let response = {
             let refresh_token = &self.token.read().await.refresh_token;
 
@@ -535,11 +543,14 @@ impl Client {
                 ("client_secret", &self.client_secret),
                 ("redirect_uri", &self.redirect_uri),
             ];
-            let client = reqwest::Client::new();
-            client
-                .post(TOKEN_ENDPOINT)
+            self.client
+                .post(self.token_endpoint())
                 .headers(headers)
-                .form(&params)
+                .header(
+                    reqwest::header::CONTENT_TYPE,
+                    "application/x-www-form-urlencoded",
+                )
+                .body(serde_urlencoded::to_string(params).unwrap())
                 .basic_auth(&self.client_id, Some(&self.client_secret))
                 .send()
                 .await?
@@ -576,11 +587,15 @@ impl Client {
             ("redirect_uri", &self.redirect_uri),
             ("state", state),
         ];
-        let client = reqwest::Client::new();
-        let resp = client
-            .post(TOKEN_ENDPOINT)
+        let resp = self
+            .client
+            .post(self.token_endpoint())
             .headers(headers)
-            .form(&params)
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded",
+            )
+            .body(serde_urlencoded::to_string(params).unwrap())
             .basic_auth(&self.client_id, Some(&self.client_secret))
             .send()
             .await?;
@@ -597,6 +612,126 @@ impl Client {
         Ok(t)
     }
 
+    /// Create a new Client struct for a Server-to-Server OAuth app.
+    ///
+    /// Access tokens are requested with the `account_credentials` grant, and are
+    /// renewed automatically shortly before they expire.
+    pub fn new_server_to_server<A, I, K>(account_id: A, client_id: I, client_secret: K) -> Self
+    where
+        A: ToString,
+        I: ToString,
+        K: ToString,
+    {
+        let mut client = Client::new(client_id, client_secret, "", "", "");
+        client.account_id = account_id.to_string();
+        client.auto_refresh = true;
+        // There is no token yet, so make sure the first request gets one.
+        client.token = Arc::new(RwLock::new(InnerToken {
+            access_token: String::new(),
+            refresh_token: String::new(),
+            expires_at: Some(Instant::now()),
+        }));
+        client
+    }
+
+    /// Create a new Client struct for a Server-to-Server OAuth app from environment variables.
+    ///
+    /// The following environment variables are expected to be set:
+    ///   * `ZOOM_ACCOUNT_ID`
+    ///   * `ZOOM_CLIENT_ID`
+    ///   * `ZOOM_CLIENT_SECRET`
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the expected environment variables can not be found
+    pub fn new_server_to_server_from_env() -> Self {
+        let account_id = env::var("ZOOM_ACCOUNT_ID").expect("must set ZOOM_ACCOUNT_ID");
+        let client_id = env::var("ZOOM_CLIENT_ID").expect("must set ZOOM_CLIENT_ID");
+        let client_secret = env::var("ZOOM_CLIENT_SECRET").expect("must set ZOOM_CLIENT_SECRET");
+
+        Client::new_server_to_server(account_id, client_id, client_secret)
+    }
+
+    /// Get an access token for a Server-to-Server OAuth app. Tokens are valid for
+    /// an hour and there is no refresh token, a new one is requested once the
+    /// current one expires.
+    ///
+    /// Concurrent callers wait for the same request rather than each making one.
+    pub async fn get_account_credentials_token(&self) -> ClientResult<AccessToken> {
+        // Hold the lock across the request, whoever waited on it gets its token.
+        let mut token = self.token.write().await;
+        let now = Instant::now();
+        if let Some(expires_at) = token.expires_at {
+            if expires_at > now && !token.access_token.is_empty() {
+                return Ok(AccessToken {
+                    token_type: "bearer".to_string(),
+                    access_token: token.access_token.clone(),
+                    expires_in: (expires_at - now + REFRESH_THRESHOLD).as_secs() as i64,
+                    ..Default::default()
+                });
+            }
+        }
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.append(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        );
+
+        let params = [
+            ("grant_type", "account_credentials"),
+            ("account_id", &self.account_id),
+        ];
+        let resp = self
+            .client
+            .post(self.token_endpoint())
+            .headers(headers)
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded",
+            )
+            .body(serde_urlencoded::to_string(params).unwrap())
+            .basic_auth(&self.client_id, Some(&self.client_secret))
+            .send()
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let headers = resp.headers().clone();
+            return Err(ClientError::HttpError {
+                status,
+                headers,
+                error: resp.text().await?,
+            });
+        }
+
+        // Unwrap the response.
+        let t: AccessToken = resp.json().await?;
+
+        *token = InnerToken {
+            access_token: t.access_token.clone(),
+            refresh_token: String::new(),
+            expires_at: Self::compute_expires_at(t.expires_in),
+        };
+
+        Ok(t)
+    }
+
+    /// Send token requests to `token_endpoint` rather than to Zoom, such as to a
+    /// proxy. The host override of the API does not apply to them.
+    pub fn with_token_endpoint<E>(&mut self, token_endpoint: E) -> &mut Self
+    where
+        E: ToString,
+    {
+        self.token_endpoint = Some(token_endpoint.to_string());
+        self
+    }
+
+    /// Where token requests go.
+    fn token_endpoint(&self) -> &str {
+        self.token_endpoint.as_deref().unwrap_or(TOKEN_ENDPOINT)
+    }
+
     async fn url_and_auth(&self, uri: &str) -> ClientResult<(reqwest::Url, Option<String>)> {
         let parsed_url = uri.parse::<reqwest::Url>()?;
 
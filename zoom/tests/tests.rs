@@ -83,3 +83,119 @@ fn test_deserialize_recorded_meetings() {
         deserialized.from.unwrap()
     );
 }
+
+fn token(access_token: &str) -> wiremock::ResponseTemplate {
+    wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "access_token": access_token,
+        "token_type": "bearer",
+        "expires_in": 3600,
+        "scope": "user:read:admin",
+    }))
+}
+
+fn account_credentials_grant() -> wiremock::MockBuilder {
+    use base64::Engine;
+    use wiremock::matchers::{body_string_contains, header, method, path};
+
+    let credentials = base64::engine::general_purpose::STANDARD.encode("client:secret");
+    wiremock::Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .and(header(
+            "Authorization",
+            format!("Basic {credentials}").as_str(),
+        ))
+        .and(header("Content-Type", "application/x-www-form-urlencoded"))
+        .and(body_string_contains("grant_type=account_credentials"))
+        .and(body_string_contains("account_id=account"))
+}
+
+async fn get_me(client: &zoom_api::Client) -> String {
+    client
+        .users()
+        .user("me", Default::default(), false)
+        .await
+        .unwrap()
+        .body
+        .user
+        .email
+}
+
+#[tokio::test]
+async fn test_server_to_server_tokens_are_renewed_once_expired() {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header, method, path},
+    };
+
+    // Tokens come from their own endpoint, not from the API host override.
+    let server = MockServer::start().await;
+    let token_server = MockServer::start().await;
+    account_credentials_grant()
+        .respond_with(token("first"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&token_server)
+        .await;
+    account_credentials_grant()
+        .respond_with(token("second"))
+        .expect(1)
+        .mount(&token_server)
+        .await;
+    for access_token in ["first", "second"] {
+        Mock::given(method("GET"))
+            .and(path("/users/me"))
+            .and(header(
+                "Authorization",
+                format!("Bearer {access_token}").as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "email": access_token,
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+    }
+
+    let mut client = zoom_api::Client::new_server_to_server("account", "client", "secret");
+    client.with_host_override(server.uri());
+    client.with_token_endpoint(format!("{}/oauth/token", token_server.uri()));
+
+    // The first request gets a token, which is used until it expires.
+    assert_eq!(get_me(&client).await, "first");
+    assert_eq!(get_me(&client).await, "first");
+
+    client.set_expires_at(Some(std::time::Instant::now())).await;
+    assert_eq!(get_me(&client).await, "second");
+    assert_eq!(get_me(&client).await, "second");
+}
+
+#[tokio::test]
+async fn test_concurrent_requests_share_a_server_to_server_token() {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header, method, path},
+    };
+
+    let server = MockServer::start().await;
+    account_credentials_grant()
+        .respond_with(token("shared").set_delay(std::time::Duration::from_millis(100)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .and(header("Authorization", "Bearer shared"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "email": "shared",
+        })))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let mut client = zoom_api::Client::new_server_to_server("account", "client", "secret");
+    client.with_host_override(server.uri());
+    client.with_token_endpoint(format!("{}/oauth/token", server.uri()));
+
+    let (a, b, c) = tokio::join!(get_me(&client), get_me(&client), get_me(&client));
+    assert_eq!([a, b, c], ["shared", "shared", "shared"]);
+}